@@ -1,19 +1,26 @@
 #!/usr/bin/env bash
 
-# Helper to estimate the final crate size by looking into "src/res_rasterized_characters" and
-# calculating its total size.
+# Helper to check the final crate size. It packages the crate like "cargo publish" does and
+# fails if the compressed crate exceeds the upload limit of crates.io.
 
 set -e
 
 ANSI_RED="\e[31m"
 ANSI_BOLD="\e[1m"
 ANSI_CLEAR="\e[0m"
-SIZE=$(du -sh src/res_rasterized_characters | sed 's/\s.*$//')
+# upload limit of crates.io
+MAX_CRATE_SIZE=$((10 * 1000 * 1000))
 
-echo -e "The final (uncompiled) crate size will be at least $ANSI_BOLD$ANSI_RED$SIZE$ANSI_CLEAR in size".
-echo "Note that"
-echo "  1) crates that use this library will only use parts that they require"
-echo "  2) when the Rust source code describing the raster is compiled, the amount of memory"
-echo "     used is significantly smaller".
-echo "Hence, the memory impact for third party crates will be significantly lower than this"
-echo "number may indicate. However, this number indicates the upload/download size of this crate."
+SIZE=$(du -sh --apparent-size src/res_rasterized_characters | sed 's/\s.*$//')
+echo -e "The rasters in the (uncompiled) crate are $ANSI_BOLD$ANSI_RED$SIZE$ANSI_CLEAR in size."
+echo "Note that crates that use this library will only use parts that they require."
+
+cargo package --quiet --allow-dirty --no-verify
+CRATE_FILE=$(ls target/package/*.crate)
+CRATE_SIZE=$(stat -c %s "$CRATE_FILE")
+echo -e "The compressed crate (upload/download size) is $ANSI_BOLD$CRATE_SIZE$ANSI_CLEAR bytes in size."
+
+if [ "$CRATE_SIZE" -gt "$MAX_CRATE_SIZE" ]; then
+    echo -e "${ANSI_RED}The crate exceeds the limit of $MAX_CRATE_SIZE bytes of crates.io!$ANSI_CLEAR"
+    exit 1
+fi
@@ -0,0 +1,108 @@
+//! Writer for BDF fonts (Glyph Bitmap Distribution Format 2.1), which X11 tools, font editors,
+//! and converters for embedded toolchains, such as u8g2, consume.
+
+use crate::bitmap_font::{glyph_name, BitmapFont, Property, RESOLUTION};
+use crate::monochrome::pack_row;
+use std::fmt::Write;
+
+/// Encodes the font as BDF 2.1. Each glyph covers its whole cell, or both cells for wide glyphs,
+/// whose `DWIDTH` advances the cursor by two cells.
+pub fn encode_bdf(font: &BitmapFont) -> String {
+    let mut bdf = String::new();
+    writeln!(bdf, "STARTFONT 2.1").unwrap();
+    writeln!(bdf, "FONT {}", font.xlfd_name()).unwrap();
+    writeln!(bdf, "SIZE {} {} {}", font.height, RESOLUTION, RESOLUTION).unwrap();
+    writeln!(
+        bdf,
+        "FONTBOUNDINGBOX {} {} 0 -{}",
+        font.glyph_width(font.max_cells()),
+        font.height,
+        font.descent()
+    )
+    .unwrap();
+
+    let properties = font.properties();
+    writeln!(bdf, "STARTPROPERTIES {}", properties.len()).unwrap();
+    for (name, value) in properties {
+        match value {
+            Property::Int(value) => writeln!(bdf, "{} {}", name, value).unwrap(),
+            Property::Str(value) => {
+                writeln!(bdf, "{} \"{}\"", name, value.replace('"', "\"\"")).unwrap()
+            }
+        }
+    }
+    writeln!(bdf, "ENDPROPERTIES").unwrap();
+
+    writeln!(bdf, "CHARS {}", font.glyphs.len()).unwrap();
+    for (c, cells, bits) in &font.glyphs {
+        let width = font.glyph_width(*cells);
+        assert_eq!(
+            bits.len(),
+            font.height,
+            "glyph of '{}' has the wrong height",
+            c
+        );
+        writeln!(bdf, "STARTCHAR {}", glyph_name(*c)).unwrap();
+        writeln!(bdf, "ENCODING {}", *c as u32).unwrap();
+        writeln!(bdf, "SWIDTH {} 0", font.scalable_width(*cells)).unwrap();
+        writeln!(bdf, "DWIDTH {} 0", width).unwrap();
+        writeln!(bdf, "BBX {} {} 0 -{}", width, font.height, font.descent()).unwrap();
+        writeln!(bdf, "BITMAP").unwrap();
+        for row in bits {
+            assert_eq!(row.len(), width, "glyph of '{}' has the wrong width", c);
+            for byte in pack_row(row) {
+                write!(bdf, "{:02X}", byte).unwrap();
+            }
+            writeln!(bdf).unwrap();
+        }
+        writeln!(bdf, "ENDCHAR").unwrap();
+    }
+    writeln!(bdf, "ENDFONT").unwrap();
+    bdf
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bitmap_font::tests::{test_font, test_font_with_wide_glyph};
+
+    #[test]
+    fn test_encode_bdf() {
+        let bdf = encode_bdf(&test_font());
+        assert!(bdf.starts_with("STARTFONT 2.1\n"));
+        assert!(bdf.ends_with("ENDFONT\n"));
+        assert!(bdf
+            .contains("FONT -Noto-Noto Sans Mono-Regular-R-Normal--3-30-72-72-C-100-ISO10646-1\n"));
+        assert!(bdf.contains("FONTBOUNDINGBOX 10 3 0 -1\n"));
+        assert!(bdf.contains("FONT_ASCENT 2\nFONT_DESCENT 1\n"));
+        assert!(bdf.contains("DEFAULT_CHAR 65533\n"));
+        assert!(bdf.contains("CHARS 2\n"));
+        assert!(bdf.contains(
+            "STARTCHAR uniFFFD\nENCODING 65533\nSWIDTH 3333 0\nDWIDTH 10 0\nBBX 10 3 0 -1\n\
+             BITMAP\n8040\nFFC0\n8040\nENDCHAR\n"
+        ));
+        let properties = bdf
+            .lines()
+            .filter(|line| line.starts_with("STARTPROPERTIES"));
+        assert_eq!(
+            properties.collect::<Vec<_>>(),
+            [format!(
+                "STARTPROPERTIES {}",
+                test_font().properties().len()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_encode_bdf_wide_glyph() {
+        let bdf = encode_bdf(&test_font_with_wide_glyph());
+        assert!(bdf.contains("FONTBOUNDINGBOX 20 3 0 -1\n"));
+        assert!(bdf.contains("CHARS 3\n"));
+        assert!(bdf.contains(
+            "STARTCHAR uni2153\nENCODING 8531\nSWIDTH 6666 0\nDWIDTH 20 0\nBBX 20 3 0 -1\n\
+             BITMAP\n800000\n000000\n000010\nENDCHAR\n"
+        ));
+        // the other glyphs keep the width of a single cell
+        assert!(bdf.contains("DWIDTH 10 0\nBBX 10 3 0 -1\n"));
+    }
+}
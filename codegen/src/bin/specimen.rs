@@ -0,0 +1,84 @@
+//! Headless helper binary that renders a specimen sheet with all glyphs of the generated crate
+//! for each font weight and raster height. Unlike "rasterize_chars_in_window", it needs no
+//! window, so glyphs can be reviewed on CI machines and in PRs.
+//!
+//! `cargo run --release --bin specimen -- <out-dir> [--ppm]`
+//!
+//! writes `specimen-<weight>-<height>.png` files into `<out-dir>`, or PPM files with `--ppm`,
+//! for the font weights and raster heights of the codegen configuration.
+//! See [`codegen::specimen`] for the layout of the sheets.
+
+use codegen::config::Config;
+use codegen::font::{find_wide_chars, RasterizationInfo};
+use codegen::specimen::{render_specimen, Specimen};
+use codegen::CODEGEN_CONFIG_PATH;
+use std::fs::create_dir_all;
+use std::path::Path;
+
+/// Raster height of the title and of the code point labels.
+const LABEL_RASTER_HEIGHT: usize = 12;
+
+fn main() {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    let out_dir = Path::new(
+        args.iter()
+            .find(|arg| !arg.starts_with("--"))
+            .expect("usage: specimen <out-dir> [--ppm]"),
+    );
+    let ppm = args.iter().any(|arg| arg == "--ppm");
+    create_dir_all(out_dir).unwrap();
+    let config = Config::load(CODEGEN_CONFIG_PATH).unwrap_or_else(|e| {
+        eprintln!("ERROR: {}", e);
+        std::process::exit(1);
+    });
+
+    let label_weight = config
+        .font_weight("regular")
+        .unwrap_or(&config.font_weights()[0]);
+    let label_font = RasterizationInfo::new(LABEL_RASTER_HEIGHT, label_weight.font());
+    let ranges = config.unicode_ranges().iter().collect::<Vec<_>>();
+    let wide_chars = find_wide_chars(&config);
+
+    for weight in config.font_weights() {
+        for height in config.raster_heights() {
+            let font = config.rasterization_info(weight, height.value() as usize);
+            let glyphs = font
+                .rasterize_ranges(&ranges, &wide_chars)
+                .into_iter()
+                .map(|(c, _, raster)| (c, raster))
+                .collect::<Vec<_>>();
+            let specimen = Specimen {
+                title: format!(
+                    "Noto Sans Mono {} {}px: {} glyphs",
+                    weight.mod_name(),
+                    height.value(),
+                    glyphs.len()
+                ),
+                width: font.raster_width(),
+                height: font.raster_height(),
+                baseline: font.baseline(),
+                glyphs,
+            };
+            let image = render_specimen(&specimen, &label_font);
+
+            let (extension, bytes) = if ppm {
+                ("ppm", image.encode_ppm())
+            } else {
+                ("png", image.encode_png())
+            };
+            let path = out_dir.join(format!(
+                "specimen-{}-{}.{}",
+                weight.mod_name(),
+                height.value(),
+                extension
+            ));
+            std::fs::write(&path, bytes).unwrap();
+            println!(
+                "INFO: wrote {}x{} px to {}",
+                image.width(),
+                image.height(),
+                path.display()
+            );
+        }
+    }
+}
@@ -0,0 +1,178 @@
+//! Description of a character-cell font with one bit per pixel, which the writers for bitmap
+//! font formats, such as [`crate::psf`], [`crate::bdf`], and [`crate::pcf`], encode.
+
+/// Resolution of the exported fonts in dots per inch. With 72 dpi, the size in points equals
+/// the size in pixels.
+pub const RESOLUTION: u32 = 72;
+
+/// A character-cell font with one bit per pixel. All glyphs have the same height. Most glyphs
+/// occupy a single cell, wide glyphs occupy two cells and are twice as wide.
+#[derive(Debug)]
+pub struct BitmapFont {
+    /// Family name, such as "Noto Sans Mono".
+    pub family_name: String,
+    /// Weight name, such as "Regular".
+    pub weight_name: String,
+    /// Width of a cell in pixels.
+    pub width: usize,
+    /// Height of each glyph in pixels.
+    pub height: usize,
+    /// Rows above the baseline. The remaining rows are the descent.
+    pub ascent: usize,
+    /// Copyright and license notice of the font.
+    pub notice: String,
+    /// Glyphs with the number of cells that they occupy, 1 or 2, and `height` rows of
+    /// `cells * width` pixels each.
+    pub glyphs: Vec<(char, usize, Vec<Vec<bool>>)>,
+}
+
+/// Value of a font property.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Property {
+    Int(i32),
+    Str(String),
+}
+
+impl BitmapFont {
+    /// Rows below the baseline.
+    pub const fn descent(&self) -> usize {
+        self.height - self.ascent
+    }
+
+    /// The X Logical Font Description (XLFD) name of the font.
+    pub fn xlfd_name(&self) -> String {
+        format!(
+            "-{}-{}-{}-R-Normal--{}-{}-{}-{}-C-{}-ISO10646-1",
+            self.foundry(),
+            self.family_name,
+            self.weight_name,
+            self.height,
+            self.height * 10,
+            RESOLUTION,
+            RESOLUTION,
+            self.width * 10
+        )
+    }
+
+    /// The properties of the font, as in the `STARTPROPERTIES` section of BDF.
+    pub fn properties(&self) -> Vec<(&'static str, Property)> {
+        use Property::{Int, Str};
+        let mut properties = vec![
+            ("FOUNDRY", Str(self.foundry().to_string())),
+            ("FAMILY_NAME", Str(self.family_name.clone())),
+            ("WEIGHT_NAME", Str(self.weight_name.clone())),
+            ("SLANT", Str("R".to_string())),
+            ("SETWIDTH_NAME", Str("Normal".to_string())),
+            ("ADD_STYLE_NAME", Str(String::new())),
+            ("PIXEL_SIZE", Int(self.height as i32)),
+            ("POINT_SIZE", Int(self.height as i32 * 10)),
+            ("RESOLUTION_X", Int(RESOLUTION as i32)),
+            ("RESOLUTION_Y", Int(RESOLUTION as i32)),
+            ("SPACING", Str("C".to_string())),
+            ("AVERAGE_WIDTH", Int(self.width as i32 * 10)),
+            ("CHARSET_REGISTRY", Str("ISO10646".to_string())),
+            ("CHARSET_ENCODING", Str("1".to_string())),
+            ("FONT_ASCENT", Int(self.ascent as i32)),
+            ("FONT_DESCENT", Int(self.descent() as i32)),
+            ("NOTICE", Str(self.notice.clone())),
+        ];
+        if let Some(default_char) = self.default_char() {
+            properties.push(("DEFAULT_CHAR", Int(default_char as i32)));
+        }
+        properties
+    }
+
+    /// The char that is shown for chars without glyph: '�', if the font has it.
+    pub fn default_char(&self) -> Option<char> {
+        self.glyphs
+            .iter()
+            .map(|(c, _, _)| *c)
+            .find(|&c| c == char::REPLACEMENT_CHARACTER)
+    }
+
+    /// Width of a glyph in pixels that occupies the given number of cells.
+    pub const fn glyph_width(&self, cells: usize) -> usize {
+        self.width * cells
+    }
+
+    /// Scalable width of a glyph that occupies the given number of cells in 1/1000 of the font
+    /// size, as in `SWIDTH` of BDF.
+    pub const fn scalable_width(&self, cells: usize) -> usize {
+        self.glyph_width(cells) * 1000 / self.height
+    }
+
+    /// The maximum number of cells of the glyphs: 2, if the font has wide glyphs.
+    pub fn max_cells(&self) -> usize {
+        self.glyphs
+            .iter()
+            .map(|(_, cells, _)| *cells)
+            .max()
+            .unwrap_or(1)
+    }
+
+    /// The foundry of the XLFD name, i.e., the first word of the family name.
+    fn foundry(&self) -> &str {
+        self.family_name.split(' ').next().unwrap_or_default()
+    }
+}
+
+/// Returns the PostScript name of the glyph of a char, such as "uni0041".
+pub fn glyph_name(c: char) -> String {
+    if (c as u32) <= 0xffff {
+        format!("uni{:04X}", c as u32)
+    } else {
+        format!("u{:X}", c as u32)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// A font with a 10x3 '�' and an empty 'A'. See [`test_font_with_wide_glyph`] for a font
+    /// with a wide glyph.
+    pub(crate) fn test_font() -> BitmapFont {
+        let mut row = vec![false; 10];
+        row[0] = true;
+        row[9] = true;
+        BitmapFont {
+            family_name: "Noto Sans Mono".to_string(),
+            weight_name: "Regular".to_string(),
+            width: 10,
+            height: 3,
+            ascent: 2,
+            notice: "OFL".to_string(),
+            glyphs: vec![
+                ('A', 1, vec![vec![false; 10]; 3]),
+                ('�', 1, vec![row.clone(), vec![true; 10], row]),
+            ],
+        }
+    }
+
+    /// [`test_font`] with a wide '⅓' of 20x3 pixels, whose top left and bottom right pixels are
+    /// set.
+    pub(crate) fn test_font_with_wide_glyph() -> BitmapFont {
+        let mut font = test_font();
+        let mut glyph = vec![vec![false; 20]; 3];
+        glyph[0][0] = true;
+        glyph[2][19] = true;
+        font.glyphs.push(('⅓', 2, glyph));
+        font
+    }
+
+    #[test]
+    fn test_properties() {
+        let font = test_font();
+        assert_eq!(font.descent(), 1);
+        assert_eq!(font.default_char(), Some('�'));
+        assert_eq!(font.scalable_width(1), 3333);
+        assert_eq!(font.max_cells(), 1);
+        assert_eq!(test_font_with_wide_glyph().max_cells(), 2);
+        assert_eq!(test_font_with_wide_glyph().scalable_width(2), 6666);
+        assert_eq!(glyph_name('A'), "uni0041");
+        assert_eq!(glyph_name('😀'), "u1F600");
+        let properties = font.properties();
+        assert!(properties.contains(&("FONT_ASCENT", Property::Int(2))));
+        assert!(properties.contains(&("FOUNDRY", Property::Str("Noto".to_string()))));
+    }
+}
@@ -0,0 +1,152 @@
+//! Procedural rasterization of the block elements (U+2580 to U+259F).
+//!
+//! Blocks must fill their raster exactly, so that they can be used for progress bars, bar
+//! charts, and half-block graphics. The glyphs of the font can't guarantee that, as they are
+//! centered in the raster with some vertical padding. Therefore, the blocks are generated from
+//! the width and the height of the raster.
+//!
+//! All blocks are described in eighths of the raster. Complementary blocks, such as '▀' and
+//! '▄' or '▘' and '▟', share the same boundary and together exactly fill the raster. The
+//! shades '░', '▒', and '▓' fill the whole raster with a uniform intensity.
+
+use crate::font::RasterizationInfo;
+use std::ops::Range;
+
+/// A rectangle of a block element in eighths of the raster width and height.
+type Rect = (Range<usize>, Range<usize>);
+
+const FULL: Rect = (0..8, 0..8);
+const UPPER_LEFT: Rect = (0..4, 0..4);
+const UPPER_RIGHT: Rect = (4..8, 0..4);
+const LOWER_LEFT: Rect = (0..4, 4..8);
+const LOWER_RIGHT: Rect = (4..8, 4..8);
+
+/// Returns the procedurally generated raster for a block element or `None` if the char is
+/// not in the range U+2580 to U+259F.
+pub fn rasterize(font: &RasterizationInfo, c: char) -> Option<Vec<Vec<u8>>> {
+    let (intensity, rects) = shape(c)?;
+    let (width, height) = (font.raster_width(), font.raster_height());
+    let mut raster = vec![vec![0; width]; height];
+    for (xs, ys) in rects {
+        for row in &mut raster[eighths(height, ys)] {
+            row[eighths(width, xs.clone())].fill(intensity);
+        }
+    }
+    Some(raster)
+}
+
+/// Maps a block element to its intensity and the rectangles that it consists of.
+fn shape(c: char) -> Option<(u8, Vec<Rect>)> {
+    let lower = |n: usize| (0..8, 8 - n..8);
+    let left = |n: usize| (0..n, 0..8);
+    let rects = match c {
+        '▀' => vec![(0..8, 0..4)],
+        '▁' => vec![lower(1)],
+        '▂' => vec![lower(2)],
+        '▃' => vec![lower(3)],
+        '▄' => vec![lower(4)],
+        '▅' => vec![lower(5)],
+        '▆' => vec![lower(6)],
+        '▇' => vec![lower(7)],
+        '█' => vec![FULL],
+        '▉' => vec![left(7)],
+        '▊' => vec![left(6)],
+        '▋' => vec![left(5)],
+        '▌' => vec![left(4)],
+        '▍' => vec![left(3)],
+        '▎' => vec![left(2)],
+        '▏' => vec![left(1)],
+        '▐' => vec![(4..8, 0..8)],
+        // shades: 25%, 50%, and 75% intensity
+        '░' => return Some((64, vec![FULL])),
+        '▒' => return Some((128, vec![FULL])),
+        '▓' => return Some((191, vec![FULL])),
+        '▔' => vec![(0..8, 0..1)],
+        '▕' => vec![(7..8, 0..8)],
+        '▖' => vec![LOWER_LEFT],
+        '▗' => vec![LOWER_RIGHT],
+        '▘' => vec![UPPER_LEFT],
+        '▙' => vec![UPPER_LEFT, LOWER_LEFT, LOWER_RIGHT],
+        '▚' => vec![UPPER_LEFT, LOWER_RIGHT],
+        '▛' => vec![UPPER_LEFT, UPPER_RIGHT, LOWER_LEFT],
+        '▜' => vec![UPPER_LEFT, UPPER_RIGHT, LOWER_RIGHT],
+        '▝' => vec![UPPER_RIGHT],
+        '▞' => vec![UPPER_RIGHT, LOWER_LEFT],
+        '▟' => vec![UPPER_RIGHT, LOWER_LEFT, LOWER_RIGHT],
+        _ => return None,
+    };
+    Some((u8::MAX, rects))
+}
+
+/// Converts a range in eighths to a range of pixels on an axis of the given length. The
+/// same boundary in eighths always maps to the same pixel.
+fn eighths(length: usize, range: Range<usize>) -> Range<usize> {
+    let boundary = |eighths: usize| (length * eighths + 4) / 8;
+    boundary(range.start)..boundary(range.end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::test_config;
+
+    #[test]
+    fn test_all_block_elements_have_a_shape() {
+        for c in (0x2580..=0x259f).map(|c| char::from_u32(c).unwrap()) {
+            assert!(shape(c).is_some(), "no shape for '{}'", c);
+        }
+        assert_eq!(shape('A'), None);
+        assert_eq!(shape('─'), None);
+    }
+
+    #[test]
+    fn test_eighths() {
+        assert_eq!(eighths(16, 0..4), 0..8);
+        assert_eq!(eighths(16, 7..8), 14..16);
+        assert_eq!(eighths(9, 0..4), 0..5);
+        assert_eq!(eighths(9, 4..8), 5..9);
+        assert_eq!(eighths(9, 0..8), 0..9);
+    }
+
+    // Complementary blocks must exactly fill the raster together without overlapping, for
+    // every raster height and font weight.
+    #[test]
+    fn test_complementary_blocks_fill_raster() {
+        let pairs = [
+            ('▀', '▄'),
+            ('▌', '▐'),
+            ('▔', '▇'),
+            ('▉', '▕'),
+            ('▘', '▟'),
+            ('▝', '▙'),
+            ('▖', '▜'),
+            ('▗', '▛'),
+            ('▚', '▞'),
+        ];
+        for height in test_config().raster_heights() {
+            for weight in test_config().font_weights() {
+                let font = RasterizationInfo::new(height.value() as usize, weight.font());
+                assert!(font
+                    .rasterize('█')
+                    .iter()
+                    .flatten()
+                    .all(|&pixel| pixel == u8::MAX));
+                for (a, b) in pairs {
+                    let (a_raster, b_raster) = (font.rasterize(a), font.rasterize(b));
+                    for (a_pixel, b_pixel) in
+                        a_raster.iter().flatten().zip(b_raster.iter().flatten())
+                    {
+                        assert_eq!(
+                            *a_pixel as usize + *b_pixel as usize,
+                            u8::MAX as usize,
+                            "'{}' and '{}' don't complement each other at height {}",
+                            a,
+                            b,
+                            height.value()
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
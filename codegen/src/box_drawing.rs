@@ -0,0 +1,647 @@
+//! Procedural rasterization of the box-drawing characters (U+2500 to U+257F).
+//!
+//! The glyphs of the font do not fill their raster: centering or truncating them in
+//! [`RasterizationInfo::rasterize`] leaves gaps between adjacent rasters. Therefore, these
+//! characters are synthesized from the geometry of the raster. Every line that leaves a
+//! raster at one edge enters the adjacent raster at exactly the same pixels. Straight lines
+//! are crisp; only arcs and diagonals are anti-aliased.
+//!
+//! The thickness of light lines is the stem width of the font weight. Heavy lines are twice
+//! as thick, double lines consist of two light lines with a gap of the same width.
+
+use crate::font::RasterizationInfo;
+use std::ops::Range;
+
+/// Sub-samples per axis and pixel for anti-aliased shapes.
+const SUPERSAMPLING: usize = 4;
+
+/// Style of a line that goes from the center of the raster to one of its edges.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Line {
+    None,
+    Light,
+    Heavy,
+    Double,
+}
+
+/// Directions, in which the lines of a box-drawing character leave the raster.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    const ALL: [Self; 4] = [Self::Up, Self::Right, Self::Down, Self::Left];
+
+    const fn opposite(self) -> Self {
+        match self {
+            Self::Up => Self::Down,
+            Self::Right => Self::Left,
+            Self::Down => Self::Up,
+            Self::Left => Self::Right,
+        }
+    }
+
+    const fn is_vertical(self) -> bool {
+        matches!(self, Self::Up | Self::Down)
+    }
+
+    /// Whether the line in this direction starts at coordinate zero of its axis.
+    const fn is_at_start(self) -> bool {
+        matches!(self, Self::Up | Self::Left)
+    }
+
+    /// The two perpendicular directions. The first one is at the start of the other axis.
+    const fn perpendicular(self) -> [Self; 2] {
+        if self.is_vertical() {
+            [Self::Left, Self::Right]
+        } else {
+            [Self::Up, Self::Down]
+        }
+    }
+}
+
+/// Lines of a character in the order up, right, down, left.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+struct Arms([Line; 4]);
+
+impl Arms {
+    const fn get(&self, direction: Direction) -> Line {
+        self.0[direction as usize]
+    }
+}
+
+/// The shape of a box-drawing character.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Shape {
+    /// Straight lines from the center to the edges.
+    Lines(Arms),
+    /// A dashed line through the whole raster.
+    Dashes {
+        line: Line,
+        vertical: bool,
+        count: usize,
+    },
+    /// A rounded corner that connects the bottom or top edge with the right or left edge.
+    Arc { up: bool, left: bool },
+    /// Diagonal lines from corner to corner.
+    Diagonals { rising: bool, falling: bool },
+}
+
+/// Returns the procedurally generated raster for a box-drawing character or `None` if the
+/// char is not in the range U+2500 to U+257F.
+pub fn rasterize(font: &RasterizationInfo, c: char) -> Option<Vec<Vec<u8>>> {
+    let shape = shape(c)?;
+    let mut canvas = Canvas::new(font);
+    match shape {
+        Shape::Lines(arms) => Direction::ALL
+            .iter()
+            .for_each(|&direction| canvas.draw_arm(arms, direction)),
+        Shape::Dashes {
+            line,
+            vertical,
+            count,
+        } => canvas.draw_dashes(line, vertical, count),
+        Shape::Arc { up, left } => canvas.draw_arc(up, left),
+        Shape::Diagonals { rising, falling } => canvas.draw_diagonals(rising, falling),
+    }
+    Some(canvas.raster)
+}
+
+/// Maps a box-drawing character to its shape.
+fn shape(c: char) -> Option<Shape> {
+    use Line::{Double as D, Heavy as H, Light as L, None as N};
+    let dashes = |line, vertical, count| Shape::Dashes {
+        line,
+        vertical,
+        count,
+    };
+    let arms = match c {
+        '─' => [N, L, N, L],
+        '━' => [N, H, N, H],
+        '│' => [L, N, L, N],
+        '┃' => [H, N, H, N],
+        '┄' => return Some(dashes(L, false, 3)),
+        '┅' => return Some(dashes(H, false, 3)),
+        '┆' => return Some(dashes(L, true, 3)),
+        '┇' => return Some(dashes(H, true, 3)),
+        '┈' => return Some(dashes(L, false, 4)),
+        '┉' => return Some(dashes(H, false, 4)),
+        '┊' => return Some(dashes(L, true, 4)),
+        '┋' => return Some(dashes(H, true, 4)),
+        '┌' => [N, L, L, N],
+        '┍' => [N, H, L, N],
+        '┎' => [N, L, H, N],
+        '┏' => [N, H, H, N],
+        '┐' => [N, N, L, L],
+        '┑' => [N, N, L, H],
+        '┒' => [N, N, H, L],
+        '┓' => [N, N, H, H],
+        '└' => [L, L, N, N],
+        '┕' => [L, H, N, N],
+        '┖' => [H, L, N, N],
+        '┗' => [H, H, N, N],
+        '┘' => [L, N, N, L],
+        '┙' => [L, N, N, H],
+        '┚' => [H, N, N, L],
+        '┛' => [H, N, N, H],
+        '├' => [L, L, L, N],
+        '┝' => [L, H, L, N],
+        '┞' => [H, L, L, N],
+        '┟' => [L, L, H, N],
+        '┠' => [H, L, H, N],
+        '┡' => [H, H, L, N],
+        '┢' => [L, H, H, N],
+        '┣' => [H, H, H, N],
+        '┤' => [L, N, L, L],
+        '┥' => [L, N, L, H],
+        '┦' => [H, N, L, L],
+        '┧' => [L, N, H, L],
+        '┨' => [H, N, H, L],
+        '┩' => [H, N, L, H],
+        '┪' => [L, N, H, H],
+        '┫' => [H, N, H, H],
+        '┬' => [N, L, L, L],
+        '┭' => [N, L, L, H],
+        '┮' => [N, H, L, L],
+        '┯' => [N, H, L, H],
+        '┰' => [N, L, H, L],
+        '┱' => [N, L, H, H],
+        '┲' => [N, H, H, L],
+        '┳' => [N, H, H, H],
+        '┴' => [L, L, N, L],
+        '┵' => [L, L, N, H],
+        '┶' => [L, H, N, L],
+        '┷' => [L, H, N, H],
+        '┸' => [H, L, N, L],
+        '┹' => [H, L, N, H],
+        '┺' => [H, H, N, L],
+        '┻' => [H, H, N, H],
+        '┼' => [L, L, L, L],
+        '┽' => [L, L, L, H],
+        '┾' => [L, H, L, L],
+        '┿' => [L, H, L, H],
+        '╀' => [H, L, L, L],
+        '╁' => [L, L, H, L],
+        '╂' => [H, L, H, L],
+        '╃' => [H, L, L, H],
+        '╄' => [H, H, L, L],
+        '╅' => [L, L, H, H],
+        '╆' => [L, H, H, L],
+        '╇' => [H, H, L, H],
+        '╈' => [L, H, H, H],
+        '╉' => [H, L, H, H],
+        '╊' => [H, H, H, L],
+        '╋' => [H, H, H, H],
+        '╌' => return Some(dashes(L, false, 2)),
+        '╍' => return Some(dashes(H, false, 2)),
+        '╎' => return Some(dashes(L, true, 2)),
+        '╏' => return Some(dashes(H, true, 2)),
+        '═' => [N, D, N, D],
+        '║' => [D, N, D, N],
+        '╒' => [N, D, L, N],
+        '╓' => [N, L, D, N],
+        '╔' => [N, D, D, N],
+        '╕' => [N, N, L, D],
+        '╖' => [N, N, D, L],
+        '╗' => [N, N, D, D],
+        '╘' => [L, D, N, N],
+        '╙' => [D, L, N, N],
+        '╚' => [D, D, N, N],
+        '╛' => [L, N, N, D],
+        '╜' => [D, N, N, L],
+        '╝' => [D, N, N, D],
+        '╞' => [L, D, L, N],
+        '╟' => [D, L, D, N],
+        '╠' => [D, D, D, N],
+        '╡' => [L, N, L, D],
+        '╢' => [D, N, D, L],
+        '╣' => [D, N, D, D],
+        '╤' => [N, D, L, D],
+        '╥' => [N, L, D, L],
+        '╦' => [N, D, D, D],
+        '╧' => [L, D, N, D],
+        '╨' => [D, L, N, L],
+        '╩' => [D, D, N, D],
+        '╪' => [L, D, L, D],
+        '╫' => [D, L, D, L],
+        '╬' => [D, D, D, D],
+        '╭' => {
+            return Some(Shape::Arc {
+                up: false,
+                left: false,
+            })
+        }
+        '╮' => {
+            return Some(Shape::Arc {
+                up: false,
+                left: true,
+            })
+        }
+        '╯' => {
+            return Some(Shape::Arc {
+                up: true,
+                left: true,
+            })
+        }
+        '╰' => {
+            return Some(Shape::Arc {
+                up: true,
+                left: false,
+            })
+        }
+        '╱' => {
+            return Some(Shape::Diagonals {
+                rising: true,
+                falling: false,
+            })
+        }
+        '╲' => {
+            return Some(Shape::Diagonals {
+                rising: false,
+                falling: true,
+            })
+        }
+        '╳' => {
+            return Some(Shape::Diagonals {
+                rising: true,
+                falling: true,
+            })
+        }
+        '╴' => [N, N, N, L],
+        '╵' => [L, N, N, N],
+        '╶' => [N, L, N, N],
+        '╷' => [N, N, L, N],
+        '╸' => [N, N, N, H],
+        '╹' => [H, N, N, N],
+        '╺' => [N, H, N, N],
+        '╻' => [N, N, H, N],
+        '╼' => [N, H, N, L],
+        '╽' => [L, N, H, N],
+        '╾' => [N, L, N, H],
+        '╿' => [H, N, L, N],
+        _ => return None,
+    };
+    Some(Shape::Lines(Arms(arms)))
+}
+
+/// Centers a band of the given thickness on an axis of the given length.
+fn band(length: usize, thickness: usize) -> Range<usize> {
+    let start = length.saturating_sub(thickness) / 2;
+    start..(start + thickness).min(length)
+}
+
+/// The two bands of a double line: two light lines with a gap of the same thickness.
+fn double_bands(length: usize, thickness: usize) -> [Range<usize>; 2] {
+    let outer = band(length, 3 * thickness);
+    [
+        outer.start..(outer.start + thickness).min(length),
+        outer.end.saturating_sub(thickness)..outer.end,
+    ]
+}
+
+/// The raster of a box-drawing character during its construction.
+struct Canvas {
+    raster: Vec<Vec<u8>>,
+    width: usize,
+    height: usize,
+    light: usize,
+    heavy: usize,
+}
+
+impl Canvas {
+    fn new(font: &RasterizationInfo) -> Self {
+        let stem_width = font.stem_width();
+        let light = (stem_width.round() as usize).max(1);
+        let heavy = ((2.0 * stem_width).round() as usize).max(light + 1);
+        Self {
+            raster: vec![vec![0; font.raster_width()]; font.raster_height()],
+            width: font.raster_width(),
+            height: font.raster_height(),
+            light,
+            heavy,
+        }
+    }
+
+    const fn thickness(&self, line: Line) -> usize {
+        match line {
+            Line::None => 0,
+            Line::Light | Line::Double => self.light,
+            Line::Heavy => self.heavy,
+        }
+    }
+
+    /// Returns the length of the axis along the given direction and the length of the
+    /// other axis.
+    const fn axis_lengths(&self, vertical: bool) -> (usize, usize) {
+        if vertical {
+            (self.height, self.width)
+        } else {
+            (self.width, self.height)
+        }
+    }
+
+    /// Fills a rectangle that is given in coordinates along and across an axis.
+    fn fill(&mut self, vertical: bool, along: Range<usize>, across: Range<usize>) {
+        let (xs, ys) = if vertical {
+            (across, along)
+        } else {
+            (along, across)
+        };
+        for y in ys {
+            self.raster[y][xs.clone()].fill(u8::MAX);
+        }
+    }
+
+    /// Draws the line from the center to the edge in the given direction. The lines of the
+    /// perpendicular directions decide where exactly the line ends in the center, so that
+    /// corners and junctions are closed.
+    fn draw_arm(&mut self, arms: Arms, direction: Direction) {
+        let line = arms.get(direction);
+        if line == Line::None {
+            return;
+        }
+        let vertical = direction.is_vertical();
+        let at_start = direction.is_at_start();
+        let (length, cross_length) = self.axis_lengths(vertical);
+        let [before, after] = direction.perpendicular().map(|d| arms.get(d));
+        let opposite = arms.get(direction.opposite());
+
+        // bands of the perpendicular lines on the axis of this line
+        let doubles = double_bands(length, self.light);
+        let (near_double, far_double) = if at_start {
+            (doubles[0].clone(), doubles[1].clone())
+        } else {
+            (doubles[1].clone(), doubles[0].clone())
+        };
+        let (light, heavy) = (self.light, self.heavy);
+        let single = |line: Line| band(length, if line == Line::Heavy { heavy } else { light });
+        // the line covers the band that it ends in
+        let along = |end_band: Range<usize>| {
+            if at_start {
+                0..end_band.end
+            } else {
+                end_band.start..length
+            }
+        };
+
+        if line == Line::Double {
+            let [before_band, after_band] = double_bands(cross_length, self.light);
+            for (side, other, across) in [(before, after, before_band), (after, before, after_band)]
+            {
+                let end_band = match (side, other) {
+                    // inner corner
+                    (Line::Double, _) => near_double.clone(),
+                    (Line::Light | Line::Heavy, _) => single(side),
+                    // outer corner
+                    (Line::None, Line::Double) => far_double.clone(),
+                    (Line::None, Line::Light | Line::Heavy) => single(other),
+                    // straight line
+                    (Line::None, Line::None) => far_double.clone(),
+                };
+                self.fill(vertical, along(end_band), across);
+            }
+        } else {
+            let thickness = self.thickness(line);
+            let end_band = if before == Line::Double || after == Line::Double {
+                // a T-junction only touches the near line; otherwise it is a corner or a
+                // crossing
+                if before == after && opposite == Line::None {
+                    near_double
+                } else {
+                    far_double
+                }
+            } else if before != Line::None || after != Line::None {
+                single(if self.thickness(before) > self.thickness(after) {
+                    before
+                } else {
+                    after
+                })
+            } else {
+                band(length, thickness)
+            };
+            self.fill(vertical, along(end_band), band(cross_length, thickness));
+        }
+    }
+
+    /// Draws a dashed line. Each segment has a gap at its end, so that the pattern continues
+    /// across adjacent rasters.
+    fn draw_dashes(&mut self, line: Line, vertical: bool, count: usize) {
+        let (length, cross_length) = self.axis_lengths(vertical);
+        let across = band(cross_length, self.thickness(line));
+        let gap = (length / count / 2).max(1);
+        for i in 0..count {
+            let start = i * length / count;
+            let end = (i + 1) * length / count;
+            let dash_end = end.saturating_sub(gap).max(start + 1);
+            self.fill(vertical, start..dash_end, across.clone());
+        }
+    }
+
+    /// Draws a quarter circle that connects the centers of two edges. The ends are straight,
+    /// so that they match the light lines of adjacent rasters.
+    fn draw_arc(&mut self, up: bool, left: bool) {
+        let half = self.light as f32 / 2.0;
+        let cx = band(self.width, self.light).start as f32 + half;
+        let cy = band(self.height, self.light).start as f32 + half;
+        // keep a straight part of at least one line width at both ends
+        let radius = (cx
+            .min(cy)
+            .min(self.width as f32 - cx)
+            .min(self.height as f32 - cy)
+            - self.light as f32)
+            .max(0.0);
+        let center_x = if left { cx - radius } else { cx + radius };
+        let center_y = if up { cy - radius } else { cy + radius };
+        // end points of the straight parts beyond the raster
+        let edge_x = if left {
+            -half
+        } else {
+            self.width as f32 + half
+        };
+        let edge_y = if up { -half } else { self.height as f32 + half };
+
+        self.draw_anti_aliased(|x, y| {
+            let in_quadrant =
+                (x - center_x) * (cx - center_x) >= 0.0 && (y - center_y) * (cy - center_y) >= 0.0;
+            let arc = if in_quadrant {
+                ((x - center_x).hypot(y - center_y) - radius).abs()
+            } else {
+                f32::INFINITY
+            };
+            let vertical = distance_to_segment((x, y), (cx, center_y), (cx, edge_y));
+            let horizontal = distance_to_segment((x, y), (center_x, cy), (edge_x, cy));
+            arc.min(vertical).min(horizontal) <= half
+        });
+    }
+
+    /// Draws the diagonals from corner to corner.
+    fn draw_diagonals(&mut self, rising: bool, falling: bool) {
+        let half = self.light as f32 / 2.0;
+        let (width, height) = (self.width as f32, self.height as f32);
+        self.draw_anti_aliased(|x, y| {
+            (rising && distance_to_line((x, y), (width, 0.0), (0.0, height)) <= half)
+                || (falling && distance_to_line((x, y), (0.0, 0.0), (width, height)) <= half)
+        });
+    }
+
+    /// Sets the intensity of each pixel to the share of its sub-samples inside the shape.
+    fn draw_anti_aliased(&mut self, is_inside: impl Fn(f32, f32) -> bool) {
+        let samples = (SUPERSAMPLING * SUPERSAMPLING) as u32;
+        for (y, row) in self.raster.iter_mut().enumerate() {
+            for (x, pixel) in row.iter_mut().enumerate() {
+                let inside = (0..SUPERSAMPLING)
+                    .flat_map(|sy| (0..SUPERSAMPLING).map(move |sx| (sx, sy)))
+                    .filter(|&(sx, sy)| {
+                        let sample =
+                            |i: usize, s: usize| i as f32 + (s as f32 + 0.5) / SUPERSAMPLING as f32;
+                        is_inside(sample(x, sx), sample(y, sy))
+                    })
+                    .count() as u32;
+                *pixel = (*pixel as u32).max(inside * u8::MAX as u32 / samples) as u8;
+            }
+        }
+    }
+}
+
+/// Distance of a point to the segment between `a` and `b`.
+fn distance_to_segment(p: (f32, f32), a: (f32, f32), b: (f32, f32)) -> f32 {
+    let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+    let length_squared = dx * dx + dy * dy;
+    let t = if length_squared == 0.0 {
+        0.0
+    } else {
+        (((p.0 - a.0) * dx + (p.1 - a.1) * dy) / length_squared).clamp(0.0, 1.0)
+    };
+    (p.0 - (a.0 + t * dx)).hypot(p.1 - (a.1 + t * dy))
+}
+
+/// Distance of a point to the infinite line through `a` and `b`.
+fn distance_to_line(p: (f32, f32), a: (f32, f32), b: (f32, f32)) -> f32 {
+    let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+    ((p.0 - a.0) * dy - (p.1 - a.1) * dx).abs() / dx.hypot(dy)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::test_config;
+
+    const BOX_DRAWING: std::ops::RangeInclusive<u32> = 0x2500..=0x257f;
+
+    #[test]
+    fn test_all_box_drawing_chars_have_a_shape() {
+        for c in BOX_DRAWING.map(|c| char::from_u32(c).unwrap()) {
+            assert!(shape(c).is_some(), "no shape for '{}'", c);
+        }
+        assert_eq!(shape('A'), None);
+        assert_eq!(shape('▀'), None);
+    }
+
+    #[test]
+    fn test_bands() {
+        assert_eq!(band(8, 1), 3..4);
+        assert_eq!(band(8, 2), 3..5);
+        assert_eq!(band(9, 3), 3..6);
+        assert_eq!(double_bands(8, 1), [2..3, 4..5]);
+        assert_eq!(double_bands(16, 2), [5..7, 9..11]);
+    }
+
+    // Lines that leave a raster at one edge must enter the adjacent raster at exactly the
+    // same pixels, for every raster height and font weight.
+    #[test]
+    fn test_lines_meet_at_edges() {
+        for height in test_config().raster_heights() {
+            for weight in test_config().font_weights() {
+                let font = RasterizationInfo::new(height.value() as usize, weight.font());
+                let width = font.raster_width();
+                let edge = |raster: &Vec<Vec<u8>>, direction: Direction| -> Vec<u8> {
+                    match direction {
+                        Direction::Up => raster[0].clone(),
+                        Direction::Down => raster[raster.len() - 1].clone(),
+                        Direction::Left => raster.iter().map(|row| row[0]).collect(),
+                        Direction::Right => raster.iter().map(|row| row[width - 1]).collect(),
+                    }
+                };
+                // arcs end in light lines
+                let arms_of = |c: char| match shape(c).unwrap() {
+                    Shape::Lines(arms) => Some((c, arms)),
+                    Shape::Arc { up, left } => {
+                        let mut arms = [Line::None; 4];
+                        arms[if up { Direction::Up } else { Direction::Down } as usize] =
+                            Line::Light;
+                        arms[if left {
+                            Direction::Left
+                        } else {
+                            Direction::Right
+                        } as usize] = Line::Light;
+                        Some((c, Arms(arms)))
+                    }
+                    _ => None,
+                };
+                // in very narrow rasters, double lines inevitably touch the left and right edge
+                let light = Canvas::new(&font).light;
+                let cramped = |arms: &Arms| {
+                    width < 3 * light + 2 && arms.0.iter().any(|&line| line == Line::Double)
+                };
+                let chars = BOX_DRAWING
+                    .map(|c| char::from_u32(c).unwrap())
+                    .filter_map(arms_of)
+                    .filter(|(_, arms)| !cramped(arms))
+                    .map(|(c, arms)| (c, arms, font.rasterize(c)))
+                    .collect::<Vec<_>>();
+
+                for (c, arms, raster) in &chars {
+                    for direction in Direction::ALL {
+                        let line = arms.get(direction);
+                        let pixels = edge(raster, direction);
+                        if line == Line::None {
+                            assert!(
+                                pixels.iter().all(|&p| p == 0),
+                                "'{}' touches the {:?} edge at height {} ({})",
+                                c,
+                                direction,
+                                height.value(),
+                                weight.mod_name()
+                            );
+                            continue;
+                        }
+                        // compare with every char that continues the line in the adjacent raster
+                        for (other, other_arms, other_raster) in &chars {
+                            if other_arms.get(direction.opposite()) == line {
+                                assert_eq!(
+                                    pixels,
+                                    edge(other_raster, direction.opposite()),
+                                    "'{}' and '{}' do not meet at height {} ({})",
+                                    c,
+                                    other,
+                                    height.value(),
+                                    weight.mod_name()
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_heavy_lines_thicker_than_light_lines() {
+        for weight in test_config().font_weights() {
+            let font = RasterizationInfo::new(16, weight.font());
+            let thickness = |c: char| {
+                font.rasterize(c)
+                    .iter()
+                    .filter(|row| row[0] == u8::MAX)
+                    .count()
+            };
+            assert!(thickness('─') >= 1);
+            assert!(thickness('━') > thickness('─'));
+            assert_eq!(thickness('═'), 2 * thickness('─'));
+        }
+    }
+}
@@ -0,0 +1,148 @@
+//! Procedural rasterization of the braille patterns (U+2800 to U+28FF).
+//!
+//! Braille patterns are used to plot graphs with 2x4 dots per character. The font has no
+//! glyphs for them. The dots are generated on a grid that divides each raster into 2 columns
+//! and 4 rows. Each dot is centered in its cell of the grid, so that the spacing between
+//! dots is also consistent across adjacent characters.
+
+use crate::font::RasterizationInfo;
+use std::ops::Range;
+
+/// Dots per braille pattern in horizontal direction.
+const COLUMNS: usize = 2;
+/// Dots per braille pattern in vertical direction.
+const ROWS: usize = 4;
+
+/// Sub-samples per axis and pixel for the anti-aliasing of big dots.
+const SUPERSAMPLING: usize = 4;
+
+/// Returns the procedurally generated raster for a braille pattern or `None` if the char is
+/// not in the range U+2800 to U+28FF.
+pub fn rasterize(font: &RasterizationInfo, c: char) -> Option<Vec<Vec<u8>>> {
+    let dots = (c as u32)
+        .checked_sub(0x2800)
+        .and_then(|dots| u8::try_from(dots).ok())?;
+    let (width, height) = (font.raster_width(), font.raster_height());
+    let mut raster = vec![vec![0; width]; height];
+    for (column, row) in (0..ROWS).flat_map(|row| (0..COLUMNS).map(move |column| (column, row))) {
+        if dots & (1 << dot_bit(column, row)) == 0 {
+            continue;
+        }
+        let xs = dot_range(width, COLUMNS, column);
+        let ys = dot_range(height, ROWS, row);
+        let radius = xs.len().min(ys.len()) as f32 / 2.0;
+        let center_x = (xs.start + xs.end) as f32 / 2.0;
+        let center_y = (ys.start + ys.end) as f32 / 2.0;
+        for y in ys {
+            for x in xs.clone() {
+                let inside = (0..SUPERSAMPLING)
+                    .flat_map(|sy| (0..SUPERSAMPLING).map(move |sx| (sx, sy)))
+                    .filter(|&(sx, sy)| {
+                        let sample_x = x as f32 + (sx as f32 + 0.5) / SUPERSAMPLING as f32;
+                        let sample_y = y as f32 + (sy as f32 + 0.5) / SUPERSAMPLING as f32;
+                        (sample_x - center_x).hypot(sample_y - center_y) <= radius
+                    })
+                    .count();
+                // small dots are squares of one or two pixels; round them only if they are big
+                raster[y][x] = if radius < 1.5 {
+                    u8::MAX
+                } else {
+                    (inside * u8::MAX as usize / (SUPERSAMPLING * SUPERSAMPLING)) as u8
+                };
+            }
+        }
+    }
+    Some(raster)
+}
+
+/// Returns the bit of a dot in the code point of a braille pattern. Dots 1 to 3 and 4 to 6
+/// are the first three rows of the left and the right column; dots 7 and 8 are the last row.
+const fn dot_bit(column: usize, row: usize) -> usize {
+    if row < 3 {
+        column * 3 + row
+    } else {
+        6 + column
+    }
+}
+
+/// Returns the pixels of a dot on an axis of the given length that is divided into `count`
+/// cells. The dot covers about half of its cell and is centered in it.
+fn dot_range(length: usize, count: usize, index: usize) -> Range<usize> {
+    let cell_start = length * index / count;
+    let cell_end = length * (index + 1) / count;
+    let cell = cell_end - cell_start;
+    // all dots have the same size, independent of rounding of the cell boundaries
+    let size = (length / count).div_ceil(2).max(1);
+    let start = cell_start + cell.saturating_sub(size) / 2;
+    start..start + size
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::test_config;
+
+    #[test]
+    fn test_dot_bits() {
+        assert_eq!(dot_bit(0, 0), 0);
+        assert_eq!(dot_bit(0, 2), 2);
+        assert_eq!(dot_bit(1, 0), 3);
+        assert_eq!(dot_bit(1, 2), 5);
+        assert_eq!(dot_bit(0, 3), 6);
+        assert_eq!(dot_bit(1, 3), 7);
+    }
+
+    #[test]
+    fn test_dot_range() {
+        assert_eq!(dot_range(16, 4, 0), 1..3);
+        assert_eq!(dot_range(16, 4, 3), 13..15);
+        assert_eq!(dot_range(9, 2, 0), 1..3);
+        assert_eq!(dot_range(8, 4, 1), 2..3);
+    }
+
+    #[test]
+    fn test_only_braille_patterns() {
+        let font = RasterizationInfo::new(16, test_config().font_weights()[0].font());
+        assert!(rasterize(&font, 'A').is_none());
+        assert!(rasterize(&font, '\u{27ff}').is_none());
+        assert!(rasterize(&font, '\u{2900}').is_none());
+        assert!(rasterize(&font, '\u{2800}')
+            .unwrap()
+            .iter()
+            .flatten()
+            .all(|&pixel| pixel == 0));
+    }
+
+    // Each dot must be visible and separated from all other dots, for every raster height.
+    #[test]
+    fn test_dots_are_separated() {
+        for height in test_config().raster_heights() {
+            let font = RasterizationInfo::new(
+                height.value() as usize,
+                test_config().font_weights()[0].font(),
+            );
+            let all = rasterize(&font, '⣿').unwrap();
+            for bit in 0..8 {
+                let single =
+                    rasterize(&font, char::from_u32(0x2800 + (1 << bit)).unwrap()).unwrap();
+                assert!(single.iter().flatten().any(|&pixel| pixel == u8::MAX));
+                // the dot is part of the full pattern
+                for (single_row, all_row) in single.iter().zip(&all) {
+                    for (single_pixel, all_pixel) in single_row.iter().zip(all_row) {
+                        assert!(single_pixel <= all_pixel);
+                    }
+                }
+            }
+            // between two adjacent dots, there is an empty row or column
+            let (width, height) = (font.raster_width(), font.raster_height());
+            for row in 0..ROWS - 1 {
+                let gap = dot_range(height, ROWS, row).end;
+                assert!(gap < dot_range(height, ROWS, row + 1).start);
+                assert!(all[gap].iter().all(|&pixel| pixel == 0));
+            }
+            let gap = dot_range(width, COLUMNS, 0).end;
+            assert!(gap < dot_range(width, COLUMNS, 1).start);
+            assert!(all.iter().all(|row| row[gap] == 0));
+        }
+    }
+}
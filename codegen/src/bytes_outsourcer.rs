@@ -1,14 +1,15 @@
 use crate::font::FontWeight;
-use std::fs::File;
-use std::io::Write;
+use crate::output::OutputSink;
+use crate::unicode::UnicodeRange;
+use crate::RasterStorage;
 use std::path::PathBuf;
 
 /// A helper structure that stores bytes into files in a given directory.
-/// These files will contain the rasterized characters. They can be included with `include!`
-/// afterwards. It names all files in a reproducible way. Each filename is influenced by the
-/// character, the font weight, and the raster height. This way, new unicode ranges can be included
-/// without invaliding old files, which would happen in the case of incremental numbers as file
-/// name.
+/// These files will contain the packed rasters of a unicode range. They can be included with
+/// `include!` or `include_bytes!` afterwards. It names all files in a reproducible way. Each
+/// filename is influenced by the unicode range, the font weight, and the raster height. This way,
+/// new unicode ranges can be included without invaliding old files, which would happen in the
+/// case of incremental numbers as file name.
 #[derive(Debug)]
 pub struct BytesToFileOutsourcer {
     out_dir: &'static str,
@@ -19,22 +20,20 @@ impl BytesToFileOutsourcer {
         Self { out_dir }
     }
 
-    /// Creates a file in the given directory with `bytes` as content.
+    /// Creates a file in the given directory of the sink with `bytes` as content.
     /// The `c` is relevant for the naming of the file.
-    pub fn outsource_bytes(&mut self, bytes: &[u8], ctx: Context) -> PathBuf {
+    pub fn outsource_bytes(
+        &mut self,
+        sink: &mut dyn OutputSink,
+        bytes: &[u8],
+        ctx: Context<'_>,
+    ) -> PathBuf {
         let path = self.generate_path(ctx);
-
-        let mut file = File::options()
-            .create(true)
-            .truncate(true)
-            .write(true)
-            .open(path.as_path())
-            .unwrap();
-        file.write_all(bytes).unwrap();
+        sink.write_file(&path, bytes.to_vec());
         path
     }
 
-    fn generate_path(&self, ctx: Context) -> PathBuf {
+    fn generate_path(&self, ctx: Context<'_>) -> PathBuf {
         let mut buf = PathBuf::new();
         buf.push(self.out_dir);
         buf.push(ctx.generate_filename());
@@ -44,19 +43,29 @@ impl BytesToFileOutsourcer {
 
 /// Context object needed to uniquely name files in a reproducible way.
 #[derive(Debug, Copy, Clone)]
-pub struct Context {
-    pub c: char,
-    pub weight: FontWeight,
+pub struct Context<'a> {
+    pub range: &'a UnicodeRange,
+    /// Whether the file contains the rasters of the wide chars of the range, which are twice as
+    /// wide and hence packed into a file of their own.
+    pub wide: bool,
+    pub weight: &'a FontWeight,
     pub height: u32,
+    pub storage: RasterStorage,
 }
 
-impl Context {
+impl Context<'_> {
     fn generate_filename(&self) -> String {
+        let extension = match self.storage {
+            RasterStorage::Source => "txt",
+            RasterStorage::Packed => "bin",
+        };
         format!(
-            "0x{:x}_h{}_w{:?}.txt",
-            self.c as u32,
+            "{}{}_h{}_w{}.{}",
+            self.range.feature_name(),
+            if self.wide { "-wide" } else { "" },
             self.height,
-            self.weight.name()
+            self.weight.variant_name(),
+            extension
         )
     }
 }
@@ -64,23 +73,49 @@ impl Context {
 #[cfg(test)]
 mod tests {
     use crate::bytes_outsourcer::{BytesToFileOutsourcer, Context};
-    use crate::font::{FontWeight, FontWeightName};
+    use crate::config::test_config;
+    use crate::font::FontWeight;
+    use crate::output::MemorySink;
+    use crate::RasterStorage;
 
     #[test]
     fn test_bytes_outsourcer() {
+        let mut sink = MemorySink::new();
         let mut outsourcer = BytesToFileOutsourcer::new("target");
+        let weight = FontWeight::new("regular", 0, false, Vec::new(), Vec::new());
+        let range = test_config().unicode_range("unicode-specials").unwrap();
         let path = outsourcer.outsource_bytes(
+            &mut sink,
             "hello world".as_bytes(),
             Context {
-                c: 'a',
-                weight: FontWeight::new(FontWeightName::Regular, false),
+                range,
+                wide: false,
+                weight: &weight,
                 height: 30,
+                storage: RasterStorage::Source,
             },
         );
 
-        // 0x61: ascii of 'a'
         assert_eq!(
-            "target/0x61_h30_wRegular.txt",
+            "target/unicode-specials_h30_wRegular.txt",
+            path.as_os_str().to_str().unwrap()
+        );
+
+        assert_eq!(sink.files()[&path], b"hello world");
+
+        let path = outsourcer.outsource_bytes(
+            &mut sink,
+            &[0, 1, 42],
+            Context {
+                range,
+                wide: true,
+                weight: &weight,
+                height: 64,
+                storage: RasterStorage::Packed,
+            },
+        );
+        assert_eq!(
+            "target/unicode-specials-wide_h64_wRegular.bin",
             path.as_os_str().to_str().unwrap()
         );
     }
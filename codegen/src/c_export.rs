@@ -0,0 +1,303 @@
+//! Writer for a C header and source file with the rasters of one font weight and raster height,
+//! for C bootloaders and firmware that can't depend on the Rust crate.
+//!
+//! The source file contains the rasters of all chars in one `static const uint8_t` array, with
+//! the same intensities (0 to 255) as the Rust modules, and an index of the code points in
+//! ascending order. `<prefix>_get_char` looks up a char with a binary search in the index, and
+//! `<prefix>_draw_char` is a reference renderer that passes each pixel to a callback. The code
+//! is plain C89 without any dependencies besides `<stddef.h>` and `<stdint.h>`.
+
+use std::fmt::Write;
+
+/// Rasters of one font weight and raster height.
+#[derive(Debug)]
+pub struct CFont {
+    /// Prefix of all identifiers and of the file names, such as `noto_sans_mono_regular_16`.
+    pub prefix: String,
+    /// Description at the top of both files, such as the name and the license of the font.
+    pub notice: String,
+    /// Width of a cell in pixels.
+    pub width: usize,
+    /// Height of each raster in pixels.
+    pub height: usize,
+    /// Chars with the number of cells they occupy and their raster, which has `height` rows of
+    /// `cells * width` intensities each.
+    pub glyphs: Vec<(char, usize, Vec<Vec<u8>>)>,
+}
+
+impl CFont {
+    /// File name of the header, which the source file includes.
+    pub fn header_name(&self) -> String {
+        format!("{}.h", self.prefix)
+    }
+
+    /// File name of the source file.
+    pub fn source_name(&self) -> String {
+        format!("{}.c", self.prefix)
+    }
+
+    /// Prefix of all macros, such as `NOTO_SANS_MONO_REGULAR_16`.
+    fn macro_prefix(&self) -> String {
+        self.prefix.to_uppercase()
+    }
+
+    /// The notice as C comment.
+    fn comment(&self) -> String {
+        let mut comment = "/*\n".to_string();
+        for line in self.notice.lines() {
+            writeln!(comment, " * {}", line.replace("*/", "* /")).unwrap();
+        }
+        comment.push_str(
+            " *\n * THIS FILE GETS AUTO GENERATED BY THE CODEGEN OF noto-sans-mono-bitmap\n */\n",
+        );
+        comment
+    }
+}
+
+/// Encodes the header with the macros and the declarations of the functions.
+pub fn encode_c_header(font: &CFont) -> String {
+    let prefix = &font.prefix;
+    let macro_prefix = font.macro_prefix();
+
+    let mut header = font.comment();
+    writeln!(header).unwrap();
+    writeln!(header, "#ifndef {}_H", macro_prefix).unwrap();
+    writeln!(header, "#define {}_H", macro_prefix).unwrap();
+    writeln!(header).unwrap();
+    writeln!(header, "#include <stddef.h>").unwrap();
+    writeln!(header, "#include <stdint.h>").unwrap();
+    writeln!(header).unwrap();
+    writeln!(header, "#ifdef __cplusplus\nextern \"C\" {{\n#endif").unwrap();
+    writeln!(header).unwrap();
+    writeln!(header, "/* Height of each raster in pixels. */").unwrap();
+    writeln!(
+        header,
+        "#define {}_RASTER_HEIGHT {}",
+        macro_prefix, font.height
+    )
+    .unwrap();
+    writeln!(
+        header,
+        "/* Width of a cell in pixels. Wide chars, such as U+FFFD, occupy two cells. */"
+    )
+    .unwrap();
+    writeln!(
+        header,
+        "#define {}_RASTER_WIDTH {}",
+        macro_prefix, font.width
+    )
+    .unwrap();
+    writeln!(header, "/* Number of chars in the font. */").unwrap();
+    writeln!(
+        header,
+        "#define {}_CHAR_COUNT {}",
+        macro_prefix,
+        font.glyphs.len()
+    )
+    .unwrap();
+    writeln!(header).unwrap();
+    writeln!(
+        header,
+        "/* Receives a pixel of {prefix}_draw_char with its intensity (0 to 255). */\n\
+         typedef void (*{prefix}_put_pixel)(void *context, int x, int y, uint8_t intensity);"
+    )
+    .unwrap();
+    writeln!(header).unwrap();
+    writeln!(
+        header,
+        "/*\n \
+         * Returns the raster of a char, which has {macro_prefix}_RASTER_HEIGHT rows of\n \
+         * cells * {macro_prefix}_RASTER_WIDTH intensities (0 to 255) each, or NULL, if the\n \
+         * font doesn't have the char. Unless cells is NULL, it receives the number of cells\n \
+         * (1 or 2) that the char occupies.\n \
+         */\n\
+         const uint8_t *{prefix}_get_char(uint32_t code_point, size_t *cells);"
+    )
+    .unwrap();
+    writeln!(header).unwrap();
+    writeln!(
+        header,
+        "/*\n \
+         * Reference renderer: passes each pixel of the raster of a char to put_pixel, with the\n \
+         * top left corner of the raster at (x, y). Returns the width of the raster in pixels,\n \
+         * i.e., the advance to the next char, or 0, if the font doesn't have the char.\n \
+         */\n\
+         size_t {prefix}_draw_char(uint32_t code_point, int x, int y,\n    \
+         {prefix}_put_pixel put_pixel, void *context);"
+    )
+    .unwrap();
+    writeln!(header).unwrap();
+    writeln!(header, "#ifdef __cplusplus\n}}\n#endif").unwrap();
+    writeln!(header).unwrap();
+    writeln!(header, "#endif /* {}_H */", macro_prefix).unwrap();
+    header
+}
+
+/// Encodes the source file with the index, the rasters, and the definitions of the functions.
+pub fn encode_c_source(font: &CFont) -> String {
+    assert!(!font.glyphs.is_empty(), "C arrays can't be empty");
+    let prefix = &font.prefix;
+    let macro_prefix = font.macro_prefix();
+
+    let mut glyphs = font.glyphs.iter().collect::<Vec<_>>();
+    glyphs.sort_by_key(|(c, _, _)| *c);
+
+    let mut index = String::new();
+    let mut data = String::new();
+    let mut offset = 0;
+    for (c, cells, raster) in glyphs {
+        assert_eq!(
+            raster.len(),
+            font.height,
+            "raster of '{}' has the wrong height",
+            c
+        );
+        writeln!(
+            index,
+            "    {{ 0x{:04X}, {}, {} }},",
+            *c as u32, offset, cells
+        )
+        .unwrap();
+        writeln!(data, "    /* U+{:04X} */", *c as u32).unwrap();
+        for row in raster {
+            assert_eq!(
+                row.len(),
+                cells * font.width,
+                "raster of '{}' has the wrong width",
+                c
+            );
+            let row = row.iter().map(u8::to_string).collect::<Vec<_>>();
+            writeln!(data, "    {},", row.join(", ")).unwrap();
+            offset += row.len();
+        }
+    }
+
+    let mut source = font.comment();
+    writeln!(source).unwrap();
+    writeln!(source, "#include \"{}\"", font.header_name()).unwrap();
+    writeln!(source).unwrap();
+    writeln!(
+        source,
+        "/* Entry of the code point index. */\n\
+         struct {prefix}_entry {{\n    \
+         uint32_t code_point;\n    \
+         /* Index of the first intensity of the raster in {prefix}_data. */\n    \
+         uint32_t offset;\n    \
+         /* Number of cells that the char occupies. */\n    \
+         uint8_t cells;\n\
+         }};"
+    )
+    .unwrap();
+    writeln!(source).unwrap();
+    writeln!(
+        source,
+        "/* Code point index in ascending order. */\n\
+         static const struct {prefix}_entry {prefix}_index[{macro_prefix}_CHAR_COUNT] = {{\n\
+         {index}}};"
+    )
+    .unwrap();
+    writeln!(source).unwrap();
+    writeln!(
+        source,
+        "/* Rasters of all chars, row by row. */\n\
+         static const uint8_t {prefix}_data[{offset}] = {{\n\
+         {data}}};"
+    )
+    .unwrap();
+    writeln!(source).unwrap();
+    writeln!(
+        source,
+        "const uint8_t *{prefix}_get_char(uint32_t code_point, size_t *cells)\n\
+         {{\n    \
+         size_t low = 0;\n    \
+         size_t high = {macro_prefix}_CHAR_COUNT;\n    \
+         while (low < high) {{\n        \
+         size_t middle = low + (high - low) / 2;\n        \
+         const struct {prefix}_entry *entry = &{prefix}_index[middle];\n        \
+         if (entry->code_point == code_point) {{\n            \
+         if (cells != NULL) {{\n                \
+         *cells = entry->cells;\n            \
+         }}\n            \
+         return &{prefix}_data[entry->offset];\n        \
+         }}\n        \
+         if (entry->code_point < code_point) {{\n            \
+         low = middle + 1;\n        \
+         }} else {{\n            \
+         high = middle;\n        \
+         }}\n    \
+         }}\n    \
+         return NULL;\n\
+         }}"
+    )
+    .unwrap();
+    writeln!(source).unwrap();
+    writeln!(
+        source,
+        "size_t {prefix}_draw_char(uint32_t code_point, int x, int y,\n    \
+         {prefix}_put_pixel put_pixel, void *context)\n\
+         {{\n    \
+         size_t cells, width, row, column;\n    \
+         const uint8_t *raster = {prefix}_get_char(code_point, &cells);\n    \
+         if (raster == NULL) {{\n        \
+         return 0;\n    \
+         }}\n    \
+         width = cells * {macro_prefix}_RASTER_WIDTH;\n    \
+         for (row = 0; row < {macro_prefix}_RASTER_HEIGHT; row++) {{\n        \
+         for (column = 0; column < width; column++) {{\n            \
+         put_pixel(context, x + (int)column, y + (int)row, raster[row * width + column]);\n        \
+         }}\n    \
+         }}\n    \
+         return width;\n\
+         }}"
+    )
+    .unwrap();
+    source
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_font() -> CFont {
+        CFont {
+            prefix: "test_font_2".to_string(),
+            notice: "Test font".to_string(),
+            width: 2,
+            height: 2,
+            glyphs: vec![
+                ('�', 2, vec![vec![1, 2, 3, 4], vec![5, 6, 7, 8]]),
+                ('A', 1, vec![vec![0, 255], vec![255, 0]]),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_encode_c_header() {
+        let header = encode_c_header(&test_font());
+        assert!(header.starts_with("/*\n * Test font\n"));
+        assert!(header.contains("#ifndef TEST_FONT_2_H\n#define TEST_FONT_2_H\n"));
+        assert!(header.contains("#define TEST_FONT_2_RASTER_HEIGHT 2\n"));
+        assert!(header.contains("#define TEST_FONT_2_RASTER_WIDTH 2\n"));
+        assert!(header.contains("#define TEST_FONT_2_CHAR_COUNT 2\n"));
+        assert!(header
+            .contains("const uint8_t *test_font_2_get_char(uint32_t code_point, size_t *cells);"));
+        assert!(header.ends_with("#endif /* TEST_FONT_2_H */\n"));
+    }
+
+    #[test]
+    fn test_encode_c_source() {
+        let source = encode_c_source(&test_font());
+        assert!(source.contains("#include \"test_font_2.h\"\n"));
+        // sorted by code point, with the offsets of the rasters
+        assert!(source.contains(
+            "test_font_2_index[TEST_FONT_2_CHAR_COUNT] = {\n    \
+             { 0x0041, 0, 1 },\n    \
+             { 0xFFFD, 4, 2 },\n};"
+        ));
+        assert!(source.contains(
+            "static const uint8_t test_font_2_data[12] = {\n    \
+             /* U+0041 */\n    0, 255,\n    255, 0,\n    \
+             /* U+FFFD */\n    1, 2, 3, 4,\n    5, 6, 7, 8,\n};"
+        ));
+    }
+}
@@ -0,0 +1,463 @@
+//! Configuration of the codegen: the raster heights, font weights, and unicode ranges of the
+//! generated crate.
+//!
+//! The configuration is read from a TOML file, by default [`crate::CODEGEN_CONFIG_PATH`], and
+//! validated before anything is generated. The font files of the font weights are relative to
+//! the configuration file. See `codegen.toml` for the format.
+
+use crate::font::{FontWeight, RasterizationInfo};
+use crate::unicode::UnicodeRange;
+use crate::RasterHeight;
+use serde::Deserialize;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// Validated configuration of the codegen with the loaded font files.
+#[derive(Debug)]
+pub struct Config {
+    raster_heights: Vec<RasterHeight>,
+    font_weights: Vec<FontWeight>,
+    unicode_ranges: Vec<UnicodeRange>,
+}
+
+/// Content of the configuration file, where the font weights refer to their font files.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ConfigFile {
+    raster_heights: Vec<RasterHeight>,
+    font_weights: Vec<FontWeightEntry>,
+    unicode_ranges: Vec<UnicodeRange>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FontWeightEntry {
+    name: String,
+    #[serde(default)]
+    default_feature: bool,
+    font: PathBuf,
+    fallback_font: PathBuf,
+}
+
+/// Reasons why the configuration can't be loaded.
+#[derive(Debug)]
+pub enum ConfigError {
+    /// The configuration file or a font file can't be read.
+    Read(PathBuf, std::io::Error),
+    /// The configuration file is not valid TOML, or it has unknown or missing keys.
+    Parse(toml::de::Error),
+    /// The configuration is inconsistent, such as overlapping unicode ranges.
+    Invalid(String),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Read(path, e) => write!(f, "can't read {}: {}", path.display(), e),
+            Self::Parse(e) => write!(f, "invalid configuration file: {}", e),
+            Self::Invalid(message) => write!(f, "invalid configuration: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+impl Config {
+    /// Reads and validates the configuration file and reads the font files that it refers to.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        let path = path.as_ref();
+        let toml =
+            std::fs::read_to_string(path).map_err(|e| ConfigError::Read(path.to_path_buf(), e))?;
+        Self::parse(&toml, path.parent().unwrap_or_else(|| Path::new("")))
+    }
+
+    /// Parses and validates a configuration. The font files are relative to `base_dir`.
+    pub fn parse(toml: &str, base_dir: &Path) -> Result<Self, ConfigError> {
+        let file = toml::from_str::<ConfigFile>(toml).map_err(ConfigError::Parse)?;
+        file.validate().map_err(ConfigError::Invalid)?;
+
+        let read_font = |path: &Path| {
+            let path = base_dir.join(path);
+            std::fs::read(&path).map_err(|e| ConfigError::Read(path, e))
+        };
+        let font_weights = file
+            .font_weights
+            .iter()
+            .enumerate()
+            .map(|(discriminant, entry)| {
+                Ok(FontWeight::new(
+                    entry.name.as_str(),
+                    discriminant,
+                    entry.default_feature,
+                    read_font(&entry.font)?,
+                    read_font(&entry.fallback_font)?,
+                ))
+            })
+            .collect::<Result<Vec<_>, ConfigError>>()?;
+
+        Ok(Self {
+            raster_heights: file.raster_heights,
+            font_weights,
+            unicode_ranges: file.unicode_ranges,
+        })
+    }
+
+    /// All raster heights that will be generated, in ascending order.
+    pub fn raster_heights(&self) -> &[RasterHeight] {
+        &self.raster_heights
+    }
+
+    /// All font weights that will be generated.
+    pub fn font_weights(&self) -> &[FontWeight] {
+        &self.font_weights
+    }
+
+    /// All unicode ranges that will be generated, in ascending order.
+    pub fn unicode_ranges(&self) -> &[UnicodeRange] {
+        &self.unicode_ranges
+    }
+
+    /// Returns the font weight with the given name, such as "regular".
+    pub fn font_weight(&self, name: &str) -> Option<&FontWeight> {
+        self.font_weights.iter().find(|w| w.mod_name() == name)
+    }
+
+    /// Returns the unicode range with the given feature name, such as "unicode-basic-latin".
+    pub fn unicode_range(&self, feature_name: &str) -> Option<&UnicodeRange> {
+        self.unicode_ranges
+            .iter()
+            .find(|range| range.feature_name() == feature_name)
+    }
+
+    /// Keeps only the font weights with the given names, in the order of the configuration. This
+    /// way, a smaller variant of the crate can be generated.
+    pub fn retain_font_weights(&mut self, names: &[&str]) -> Result<(), ConfigError> {
+        if let Some(name) = names.iter().find(|&&name| self.font_weight(name).is_none()) {
+            return Err(ConfigError::Invalid(format!(
+                "unknown font weight '{}'",
+                name
+            )));
+        }
+        self.font_weights
+            .retain(|weight| names.contains(&weight.mod_name()));
+        Ok(())
+    }
+
+    /// Keeps only the raster heights with the given values, like
+    /// [`Self::retain_font_weights`].
+    pub fn retain_raster_heights(&mut self, values: &[u32]) -> Result<(), ConfigError> {
+        if let Some(value) = values
+            .iter()
+            .find(|&&value| self.raster_heights.iter().all(|h| h.value() != value))
+        {
+            return Err(ConfigError::Invalid(format!(
+                "unknown raster height {}",
+                value
+            )));
+        }
+        self.raster_heights
+            .retain(|height| values.contains(&height.value()));
+        Ok(())
+    }
+
+    /// Returns the font of a font weight for a raster height, with the fallback font for the
+    /// unicode ranges that allow it.
+    pub fn rasterization_info(
+        &self,
+        weight: &FontWeight,
+        raster_height: usize,
+    ) -> RasterizationInfo {
+        RasterizationInfo::new(raster_height, weight.font())
+            .with_fallback_font(weight.fallback_font(), &self.unicode_ranges)
+    }
+}
+
+impl ConfigFile {
+    /// Checks everything that the generated crate relies on, such as that a char belongs to at
+    /// most one unicode range. Returns a message about the first problem.
+    fn validate(&self) -> Result<(), String> {
+        if self.raster_heights.is_empty() {
+            return Err("there must be at least one raster height".to_string());
+        }
+        if self.raster_heights.iter().any(|height| height.value() == 0) {
+            return Err("raster heights must not be 0".to_string());
+        }
+        // The generated `RasterHeight::ALL` promises an ascending order.
+        for (a, b) in self
+            .raster_heights
+            .iter()
+            .zip(self.raster_heights.iter().skip(1))
+        {
+            if a.value() >= b.value() {
+                return Err(format!(
+                    "raster heights {} and {} are not in ascending order",
+                    a.value(),
+                    b.value()
+                ));
+            }
+        }
+
+        if self.font_weights.is_empty() {
+            return Err("there must be at least one font weight".to_string());
+        }
+        for (i, weight) in self.font_weights.iter().enumerate() {
+            // the name is used for a module, a Cargo feature, and an enum variant
+            let is_identifier = weight.name.starts_with(|c: char| c.is_ascii_lowercase())
+                && weight
+                    .name
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+            if !is_identifier {
+                return Err(format!(
+                    "font weight '{}' must be a lowercase identifier",
+                    weight.name
+                ));
+            }
+            if self.font_weights[..i].iter().any(|w| w.name == weight.name) {
+                return Err(format!("font weight '{}' is not unique", weight.name));
+            }
+        }
+
+        if self.unicode_ranges.is_empty() {
+            return Err("there must be at least one unicode range".to_string());
+        }
+        for (i, range) in self.unicode_ranges.iter().enumerate() {
+            // the feature name is used for a Cargo feature, for the files of the rasters, and, with
+            // underscores, for identifiers
+            let is_feature_name = range
+                .feature_name
+                .starts_with(|c: char| c.is_ascii_lowercase())
+                && range
+                    .feature_name
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_');
+            if !is_feature_name {
+                return Err(format!(
+                    "unicode range '{}' must have a lowercase feature name of letters, digits, '-', \
+                    and '_'",
+                    range.feature_name
+                ));
+            }
+            if range.begin >= range.end {
+                return Err(format!(
+                    "unicode range '{}' must begin before its end",
+                    range.feature_name
+                ));
+            }
+            if self.unicode_ranges[..i]
+                .iter()
+                .any(|r| r.feature_name == range.feature_name)
+            {
+                return Err(format!(
+                    "unicode range '{}' is not unique",
+                    range.feature_name
+                ));
+            }
+            for ignored in &range.ignored_symbols {
+                if ignored.start() > ignored.end()
+                    || *ignored.start() < range.begin
+                    || *ignored.end() > range.end
+                {
+                    return Err(format!(
+                        "ignored symbols {:#x}..={:#x} of unicode range '{}' are empty or outside \
+                        of it",
+                        ignored.start(),
+                        ignored.end(),
+                        range.feature_name
+                    ));
+                }
+            }
+        }
+        // As all ranges begin before their end, this also ensures that they don't overlap.
+        for (a, b) in self
+            .unicode_ranges
+            .iter()
+            .zip(self.unicode_ranges.iter().skip(1))
+        {
+            if a.end >= b.begin {
+                return Err(format!(
+                    "unicode ranges '{}' and '{}' overlap or are not in ascending order",
+                    a.feature_name, b.feature_name
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The configuration of the codegen, loaded once for all tests.
+#[cfg(test)]
+pub(crate) fn test_config() -> &'static Config {
+    static CONFIG: std::sync::OnceLock<Config> = std::sync::OnceLock::new();
+    CONFIG.get_or_init(|| {
+        Config::load(Path::new(env!("CARGO_MANIFEST_DIR")).join(crate::CODEGEN_CONFIG_PATH))
+            .unwrap()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RasterStorage;
+
+    const MINIMAL_CONFIG: &str = r#"
+        raster_heights = [
+            { value = 8, storage = "source" },
+            { value = 16, default_feature = true },
+        ]
+
+        [[font_weights]]
+        name = "regular"
+        font = "src/res/NotoSansMono-Regular.ttf"
+        fallback_font = "src/res/DejaVuSansMono.ttf"
+
+        [[unicode_ranges]]
+        feature_name = "unicode-a"
+        begin = 0x20
+        end = 0x7f
+        ignored_symbols = [[0x7f, 0x7f]]
+
+        [[unicode_ranges]]
+        feature_name = "unicode-b"
+        begin = 0x80
+        end = 0xff
+        fallback_font = true
+    "#;
+
+    fn parse(toml: &str) -> Result<Config, ConfigError> {
+        Config::parse(toml, Path::new(env!("CARGO_MANIFEST_DIR")))
+    }
+
+    #[test]
+    fn test_codegen_config() {
+        let config = test_config();
+        assert_eq!(config.raster_heights().len(), 11);
+        assert_eq!(config.font_weights().len(), 3);
+        assert_eq!(config.unicode_ranges().len(), 25);
+        let regular = config.font_weight("regular").unwrap();
+        assert!(regular.default_feature());
+        assert_eq!(regular.variant_name(), "Regular");
+        assert_eq!(regular.discriminant(), 1);
+        assert!(
+            config
+                .unicode_range("unicode-basic-latin")
+                .unwrap()
+                .default_feature
+        );
+        assert!(
+            config
+                .unicode_range("unicode-dingbats")
+                .unwrap()
+                .fallback_font
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        let config = parse(MINIMAL_CONFIG).unwrap();
+        let heights = config.raster_heights();
+        assert_eq!(
+            (heights[0].value(), heights[0].default_feature()),
+            (8, false)
+        );
+        assert_eq!(heights[0].storage(), RasterStorage::Source);
+        assert_eq!(heights[1].storage(), RasterStorage::Packed);
+        assert!(!config.font_weights()[0].font().is_empty());
+        let range = config.unicode_range("unicode-a").unwrap();
+        assert_eq!(range.ignored_symbols, [0x7f..=0x7f]);
+        assert_eq!(range.iter().last(), Some('~'));
+        assert!(!range.default_feature && !range.fallback_font);
+        assert!(config
+            .rasterization_info(&config.font_weights()[0], 16)
+            .has_glyph('A'));
+    }
+
+    #[test]
+    fn test_retain() {
+        let mut config = parse(MINIMAL_CONFIG).unwrap();
+        config.retain_raster_heights(&[16]).unwrap();
+        assert_eq!(config.raster_heights().len(), 1);
+        assert_eq!(config.raster_heights()[0].value(), 16);
+        assert!(matches!(
+            config.retain_raster_heights(&[16, 8]),
+            Err(ConfigError::Invalid(e)) if e == "unknown raster height 8"
+        ));
+        config.retain_font_weights(&["regular"]).unwrap();
+        assert_eq!(config.font_weights().len(), 1);
+        assert!(config.retain_font_weights(&["bold"]).is_err());
+        assert_eq!(config.font_weights().len(), 1);
+    }
+
+    #[test]
+    fn test_invalid_configs() {
+        let invalid = [
+            ("value = 16", "value = 8", "raster heights 8 and 8"),
+            ("value = 8", "value = 0", "must not be 0"),
+            (
+                "name = \"regular\"",
+                "name = \"Regular\"",
+                "lowercase identifier",
+            ),
+            (
+                "\"unicode-a\"",
+                "\"Unicode A\"",
+                "'Unicode A' must have a lowercase feature name",
+            ),
+            (
+                "\"unicode-a\"",
+                "\"1-unicode\"",
+                "'1-unicode' must have a lowercase feature name",
+            ),
+            (
+                "end = 0x7f\n",
+                "end = 0x20\n",
+                "'unicode-a' must begin before its end",
+            ),
+            (
+                "\"unicode-b\"",
+                "\"unicode-a\"",
+                "'unicode-a' is not unique",
+            ),
+            (
+                "[[0x7f, 0x7f]]",
+                "[[0x7f, 0x80]]",
+                "0x7f..=0x80 of unicode range",
+            ),
+            (
+                "[[0x7f, 0x7f]]",
+                "[[0x10, 0x20]]",
+                "0x10..=0x20 of unicode range",
+            ),
+            (
+                "[[0x7f, 0x7f]]",
+                "[[0x7f, 0x7e]]",
+                "0x7f..=0x7e of unicode range",
+            ),
+            (
+                "begin = 0x80",
+                "begin = 0x7f",
+                "'unicode-a' and 'unicode-b' overlap",
+            ),
+        ];
+        for (from, to, message) in invalid {
+            let toml = MINIMAL_CONFIG.replace(from, to);
+            assert_ne!(toml, MINIMAL_CONFIG, "'{}' is not in the config", from);
+            match parse(&toml) {
+                Err(ConfigError::Invalid(e)) => assert!(e.contains(message), "{}", e),
+                other => panic!("expected '{}', got {:?}", message, other),
+            }
+        }
+
+        let descending = MINIMAL_CONFIG
+            .replace("begin = 0x80", "begin = 0x00")
+            .replace("end = 0xff", "end = 0x1f");
+        assert!(
+            matches!(parse(&descending), Err(ConfigError::Invalid(e)) if e.contains("ascending"))
+        );
+
+        let unknown_key = MINIMAL_CONFIG.replace("fallback_font = true", "fallback = true");
+        assert!(matches!(parse(&unknown_key), Err(ConfigError::Parse(_))));
+        let missing_font = MINIMAL_CONFIG.replace("Regular.ttf", "Missing.ttf");
+        assert!(matches!(parse(&missing_font), Err(ConfigError::Read(..))));
+    }
+}
@@ -0,0 +1,99 @@
+//! Procedural rasterization of the control pictures (U+2400 to U+2421 and U+2424).
+//!
+//! Control pictures, such as '␀' or '␛', make control characters visible on the screen. The
+//! font has no glyphs for them. They are composed from the letters of the abbreviation of the
+//! control character, which are scaled down to fit into a 2x2 grid of the raster: two letters
+//! are placed on the diagonal, for three letters, the first two share the top row.
+
+use crate::font::RasterizationInfo;
+
+/// The abbreviations of U+2400 to U+2421 in code point order.
+const ABBREVIATIONS: [&str; 34] = [
+    "NUL", "SOH", "STX", "ETX", "EOT", "ENQ", "ACK", "BEL", "BS", "HT", "LF", "VT", "FF", "CR",
+    "SO", "SI", "DLE", "DC1", "DC2", "DC3", "DC4", "NAK", "SYN", "ETB", "CAN", "EM", "SUB", "ESC",
+    "FS", "GS", "RS", "US", "SP", "DEL",
+];
+
+/// Returns the procedurally generated raster for a control picture or `None` if the char is
+/// not a control picture that is composed of letters.
+pub fn rasterize(font: &RasterizationInfo, c: char) -> Option<Vec<Vec<u8>>> {
+    let abbreviation = abbreviation(c)?;
+    let (width, height) = (font.raster_width(), font.raster_height());
+    let cells = [(0, 0), (1, 1)];
+    let cells = match abbreviation.len() {
+        2 => &cells[..],
+        _ => &[(0, 0), (1, 0), (1, 1)],
+    };
+    let mut raster = vec![vec![0; width]; height];
+    for (letter, &(column, row)) in abbreviation.chars().zip(cells) {
+        let (x_start, x_end) = (width * column / 2, width * (column + 1) / 2);
+        let (y_start, y_end) = (height * row / 2, height * (row + 1) / 2);
+        // keep a gap of one pixel to the letter to the right
+        let letter_width = (x_end - x_start).saturating_sub(1).max(1);
+        let bitmap = font.rasterize_scaled(letter, letter_width, y_end - y_start);
+        for (y, bitmap_row) in bitmap.iter().enumerate() {
+            for (x, &intensity) in bitmap_row.iter().enumerate() {
+                let pixel = &mut raster[y_start + y][x_start + x];
+                *pixel = (*pixel).max(intensity);
+            }
+        }
+    }
+    Some(raster)
+}
+
+/// Returns the abbreviation of a control picture, such as "NUL" for '␀'.
+const fn abbreviation(c: char) -> Option<&'static str> {
+    match c as u32 {
+        code @ 0x2400..=0x2421 => Some(ABBREVIATIONS[(code - 0x2400) as usize]),
+        0x2424 => Some("NL"),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::test_config;
+
+    #[test]
+    fn test_abbreviations() {
+        assert_eq!(abbreviation('␀'), Some("NUL"));
+        assert_eq!(abbreviation('␉'), Some("HT"));
+        assert_eq!(abbreviation('␛'), Some("ESC"));
+        assert_eq!(abbreviation('␠'), Some("SP"));
+        assert_eq!(abbreviation('␡'), Some("DEL"));
+        assert_eq!(abbreviation('␤'), Some("NL"));
+        // taken from the font or not supported at all
+        assert_eq!(abbreviation('␣'), None);
+        assert_eq!(abbreviation('A'), None);
+    }
+
+    // Each letter of the abbreviation must be visible in its quarter of the raster.
+    #[test]
+    fn test_all_letters_visible() {
+        for height in test_config().raster_heights() {
+            let font = RasterizationInfo::new(
+                height.value() as usize,
+                test_config().font_weights()[0].font(),
+            );
+            let (width, height) = (font.raster_width(), font.raster_height());
+            let quarter_has_ink = |raster: &Vec<Vec<u8>>, column: usize, row: usize| {
+                raster[height * row / 2..height * (row + 1) / 2]
+                    .iter()
+                    .any(|pixels| {
+                        pixels[width * column / 2..width * (column + 1) / 2]
+                            .iter()
+                            .any(|&pixel| pixel > 0)
+                    })
+            };
+            let nul = rasterize(&font, '␀').unwrap();
+            assert!(quarter_has_ink(&nul, 0, 0));
+            assert!(quarter_has_ink(&nul, 1, 0));
+            assert!(quarter_has_ink(&nul, 1, 1));
+            assert!(!quarter_has_ink(&nul, 0, 1));
+            let lf = rasterize(&font, '␊').unwrap();
+            assert!(quarter_has_ink(&lf, 0, 0));
+            assert!(quarter_has_ink(&lf, 1, 1));
+        }
+    }
+}
@@ -1,8 +1,7 @@
+use crate::config::Config;
+use crate::unicode::UnicodeRange;
 use fontdue::{Font, FontSettings};
-
-const NOTO_SANS_MONO_REGULAR: &[u8] = include_bytes!("res/NotoSansMono-Regular.ttf");
-const NOTO_SANS_MONO_BOLD: &[u8] = include_bytes!("res/NotoSansMono-Bold.ttf");
-const NOTO_SANS_MONO_LIGHT: &[u8] = include_bytes!("res/NotoSansMono-Light.ttf");
+use std::collections::BTreeSet;
 
 /// Additional horizontal padding for each rasterized character in its raster/box. All chars
 /// will be centered in that box. With this value, we can influence the spacing of the text
@@ -11,76 +10,103 @@ const RASTERIZED_FONT_ADDITIONAL_PADDING: usize = 0;
 
 const FONT_SIZE_TO_RASTER_HEIGHT_RATIO: f32 = 0.84;
 
-/// All available fonts. Must match the order in [`FontWeight`]!
-const NOTO_SANS_FAMILY: [&[u8]; 3] = [
-    // must match order in enum FontWeightName
-    NOTO_SANS_MONO_LIGHT,
-    NOTO_SANS_MONO_REGULAR,
-    NOTO_SANS_MONO_BOLD,
-];
-
-pub const fn noto_font_by_weight(typ: &FontWeight) -> &'static [u8] {
-    NOTO_SANS_FAMILY[typ.name.val()]
-}
-
-/// Font weights that the codegen process generates.
-pub const SUPPORTED_FONT_WEIGHTS: &[FontWeight] = &[
-    FontWeight::new(FontWeightName::Light, false),
-    FontWeight::new(FontWeightName::Regular, true),
-    FontWeight::new(FontWeightName::Bold, false),
-];
-
-/// Supported font weights for the code generation. Corresponds to the available TTF font files.
-#[derive(Debug, Copy, Clone)]
+/// Relative amount the rasterized characters are moved to the top inside their raster.
+const VERTICAL_OFFSET_TO_RASTER_HEIGHT_RATIO: f32 = 0.07;
+
+/// Raster heights below this value use [`SMALL_FONT_SIZE_TO_RASTER_HEIGHT_RATIO`] and no
+/// vertical offset.
+const SMALL_RASTER_HEIGHT_THRESHOLD: usize = 16;
+
+/// For small raster heights, a single pixel of padding is relatively big. With the regular
+/// ratio, letters such as 'Ä', 'g', and 'y' are cut at the top or at the bottom. I figured the
+/// value out with the "rasterize_chars_in_window" binary. With this value, no char of Basic
+/// Latin, Latin-1 Supplement, and Latin Extended-A gets clipped for any font weight at raster
+/// heights 8, 10, 12, and 14, see the tests. Letters with two stacked diacritics in Latin
+/// Extended-B and Latin Extended Additional, such as 'Ǘ' and 'Ấ', are still cut at the top.
+const SMALL_FONT_SIZE_TO_RASTER_HEIGHT_RATIO: f32 = 0.75;
+
+/// Raster heights above this value use [`LARGE_FONT_SIZE_TO_RASTER_HEIGHT_RATIO`] and no
+/// vertical offset.
+const LARGE_RASTER_HEIGHT_THRESHOLD: usize = 32;
+
+/// For large raster heights, the regular ratio cuts capital letters with diacritics, such
+/// as 'Å', at the top. Figured out the same way as [`SMALL_FONT_SIZE_TO_RASTER_HEIGHT_RATIO`]
+/// for raster heights 40, 48, and 64.
+const LARGE_FONT_SIZE_TO_RASTER_HEIGHT_RATIO: f32 = 0.78;
+
+/// Factor by which a glyph may be wider than a raster before it occupies two cells, see
+/// [`RasterizationInfo::is_wide`]. Many glyphs of Noto Sans Mono, such as '⊕', are a little
+/// wider than a raster. When they are cut to the raster width, they only lose their
+/// antialiased edges. Glyphs that are more than a quarter wider, such as '※', would lose their
+/// shape.
+const WIDE_GLYPH_TOLERANCE: f32 = 1.25;
+
+/// Font weight of the code generation with its font files, see [`crate::config`].
+#[derive(Debug, Clone)]
 pub struct FontWeight {
-    name: FontWeightName,
+    // lowercase, such as "regular"
+    name: String,
+    // position in the configuration file, before any font weights are filtered out
+    discriminant: usize,
     // if the feature is included by default in Cargo.toml
     default_feature: bool,
+    // raw bytes of the font file
+    font: Vec<u8>,
+    // raw bytes of the font file for chars that the font doesn't have
+    fallback_font: Vec<u8>,
 }
 
 impl FontWeight {
-    pub const fn new(name: FontWeightName, default_feature: bool) -> Self {
+    pub fn new(
+        name: impl Into<String>,
+        discriminant: usize,
+        default_feature: bool,
+        font: Vec<u8>,
+        fallback_font: Vec<u8>,
+    ) -> Self {
         Self {
-            name,
+            name: name.into(),
+            discriminant,
             default_feature,
+            font,
+            fallback_font,
         }
     }
 
-    pub fn name(&self) -> &FontWeightName {
+    /// Returns the lowercase name, which is also the name of the module and of the Cargo
+    /// feature in the generated crate.
+    pub fn mod_name(&self) -> &str {
         &self.name
     }
 
-    pub fn mod_name(&self) -> &str {
-        self.name.mod_name()
+    /// Returns the name of the enum variant in the generated crate, such as "Regular".
+    pub fn variant_name(&self) -> String {
+        let mut chars = self.name.chars();
+        chars
+            .next()
+            .map(|first| first.to_uppercase().chain(chars).collect())
+            .unwrap_or_default()
     }
 
-    pub fn default_feature(&self) -> bool {
-        self.default_feature
+    /// Returns the discriminant of the enum variant in the generated crate. It is the position
+    /// of the font weight in the configuration file, so that it doesn't change when some font
+    /// weights are left out, e.g., with `--only`.
+    pub const fn discriminant(&self) -> usize {
+        self.discriminant
     }
-}
 
-#[derive(Debug, Copy, Clone)]
-#[repr(usize)]
-pub enum FontWeightName {
-    // must match order in array NOTO_SANS_FAMILY
-    Light,
-    Regular,
-    Bold,
-}
+    pub const fn default_feature(&self) -> bool {
+        self.default_feature
+    }
 
-impl FontWeightName {
-    /// Returns the numeric value of the enum variant.
-    pub const fn val(self) -> usize {
-        self as _
+    /// Returns the raw bytes of the font file.
+    pub fn font(&self) -> &[u8] {
+        &self.font
     }
 
-    /// Returns a lowercase string describing the font weight.
-    pub const fn mod_name(self) -> &'static str {
-        match self {
-            Self::Light => "light",
-            Self::Regular => "regular",
-            Self::Bold => "bold",
-        }
+    /// Returns the raw bytes of the fallback font file.
+    pub fn fallback_font(&self) -> &[u8] {
+        &self.fallback_font
     }
 }
 
@@ -106,7 +132,8 @@ macro_rules! trim_index_to_bounds {
 /// into a bitmap font. Currently, the usage of Noto Sans Mono is hard-coded.
 ///
 /// Guarantees, that each font raster centers the letter in a vertical and horizontal way.
-/// It might truncate letters to the left or right, especially for wide characters, such as '�'.
+/// It might truncate letters to the left or right, if they are slightly wider than the raster.
+/// Much wider characters, such as '�', can be rasterized into two cells instead.
 /// There will be a small vertical padding to other lines (if rendered as multiline) but almost no
 /// padding to the left and right by default. This way, letters can be displayed side by side and
 /// appear as mono-space font.
@@ -117,6 +144,11 @@ macro_rules! trim_index_to_bounds {
 pub struct RasterizationInfo {
     /// See [`Font`].
     font: Font,
+    /// Optional font for chars that [`Self::font`] doesn't have. See
+    /// [`Self::with_fallback_font`].
+    fallback_font: Option<Font>,
+    /// The unicode ranges whose chars may be taken from [`Self::fallback_font`].
+    fallback_ranges: Vec<UnicodeRange>,
     /// Height of the raster for the font rasterization.
     raster_height: usize,
     /// Width of each letter for the font rasterization.
@@ -125,6 +157,8 @@ pub struct RasterizationInfo {
     /// This is a little lower value than the height and is required to place the
     /// letter inside the box without clipping at the top or at the bottom.
     font_size: f32,
+    /// Amount of pixels every letter is moved to the top inside its raster.
+    vertical_offset: isize,
     /// The widest char/symbol after rasterization. Interesting as debug information which
     /// letter is the widest. Helps me to streamling the width for the centering of all letters.
     widest_char: char,
@@ -142,7 +176,19 @@ impl RasterizationInfo {
         // of letters such as "Ä" and "y". I figured the value out just by trying
         // with my "rasterize_chars_in_window" binary. It depends on the y_offset
         // in `rasterize_to_bitmap()`
-        let font_size = (raster_height as f32 * FONT_SIZE_TO_RASTER_HEIGHT_RATIO).ceil();
+        let (font_size_ratio, vertical_offset_ratio) =
+            if raster_height < SMALL_RASTER_HEIGHT_THRESHOLD {
+                (SMALL_FONT_SIZE_TO_RASTER_HEIGHT_RATIO, 0.0)
+            } else if raster_height > LARGE_RASTER_HEIGHT_THRESHOLD {
+                (LARGE_FONT_SIZE_TO_RASTER_HEIGHT_RATIO, 0.0)
+            } else {
+                (
+                    FONT_SIZE_TO_RASTER_HEIGHT_RATIO,
+                    VERTICAL_OFFSET_TO_RASTER_HEIGHT_RATIO,
+                )
+            };
+        let font_size = (raster_height as f32 * font_size_ratio).ceil();
+        let vertical_offset = (raster_height as f32 * vertical_offset_ratio) as isize;
 
         let font = Font::from_bytes(
             font_bytes,
@@ -157,23 +203,143 @@ impl RasterizationInfo {
 
         Self {
             font,
+            fallback_font: None,
+            fallback_ranges: Vec::new(),
             raster_height,
             raster_width,
             font_size,
+            vertical_offset,
             widest_char,
         }
     }
 
+    /// Adds a second font for chars that the main font doesn't have. The raster size and the
+    /// font size are still determined by the main font. Only the chars of the given unicode
+    /// ranges that allow it are taken from the fallback font, see
+    /// [`crate::unicode::uses_fallback_font`].
+    pub fn with_fallback_font(mut self, font_bytes: &[u8], ranges: &[UnicodeRange]) -> Self {
+        let font = Font::from_bytes(
+            font_bytes,
+            FontSettings {
+                scale: self.font_size,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        self.fallback_font.replace(font);
+        self.fallback_ranges = ranges
+            .iter()
+            .filter(|range| range.fallback_font)
+            .cloned()
+            .collect();
+        self
+    }
+
     /// Rasterizes a char for the given [`Self`] object into a raster/box. Every letter in the
     /// resulting mono font is horizontal and vertical aligned to the center. Furthermore,
     /// the resulting mono font contains already a vertical line spacing of a few pixels, but
     /// almost no padding to the left and right. This way, letters can be displayed side by side
     /// and appear as mono-space font.
+    ///
+    /// Box-drawing characters, block elements, and braille patterns are not taken from the font
+    /// but generated from the geometry of the raster, so that they meet at the edges of adjacent
+    /// rasters. See [`crate::box_drawing`], [`crate::block_elements`], and [`crate::braille`].
+    /// The control pictures are composed from small letters, see [`crate::control_pictures`].
+    ///
+    /// Chars that the font doesn't have are taken from the fallback font, if there is one.
     pub fn rasterize(&self, c: char) -> Vec<Vec<u8>> {
-        let (metrics, fontdue_bitmap) = self.font.rasterize(c, self.font_size);
+        self.rasterize_cells(c, 1)
+    }
+
+    /// Like [`Self::rasterize`], but the raster spans the given number of cells, i.e., it is
+    /// `cells` times as wide. Used for wide chars, see [`Self::is_wide`]. Procedurally generated
+    /// chars always fill a single cell.
+    pub fn rasterize_cells(&self, c: char, cells: usize) -> Vec<Vec<u8>> {
+        self.rasterize_procedurally(c)
+            .unwrap_or_else(|| self.rasterize_glyph(c, self.raster_width * cells))
+    }
+
+    /// Returns true if the glyph of a char is so much wider than a raster that it would lose
+    /// its shape if it was cut to the raster width, such as '�' or '⅓'. These chars should
+    /// occupy two cells. Glyphs that are at most [`WIDE_GLYPH_TOLERANCE`] times as wide as the
+    /// raster are cut as before, and combining marks, which don't advance the cursor, are never
+    /// wide. Glyphs of the fallback font are scaled down instead.
+    pub fn is_wide(&self, c: char) -> bool {
+        if !std::ptr::eq(self.font_for_glyph(c).unwrap_or(&self.font), &self.font)
+            || self.rasterize_procedurally(c).is_some()
+            || self.font.metrics(c, self.font_size).advance_width == 0.0
+        {
+            return false;
+        }
+        let (metrics, _) = self.font.rasterize(c, self.font_size);
+        metrics.width as f32 > self.raster_width as f32 * WIDE_GLYPH_TOLERANCE
+    }
+
+    /// Rasterizes all chars of the unicode ranges that the font has, in the order of the
+    /// ranges, like the generated crate. Returns each char with the number of cells that it
+    /// occupies and its raster. The chars in `wide_chars` occupy two cells, see
+    /// [`find_wide_chars`].
+    pub fn rasterize_ranges(
+        &self,
+        ranges: &[&UnicodeRange],
+        wide_chars: &BTreeSet<char>,
+    ) -> Vec<(char, usize, Vec<Vec<u8>>)> {
+        ranges
+            .iter()
+            .flat_map(|range| range.iter())
+            .filter(|&char| self.has_glyph(char))
+            .map(|char| {
+                let cells = if wide_chars.contains(&char) { 2 } else { 1 };
+                (char, cells, self.rasterize_cells(char, cells))
+            })
+            .collect()
+    }
+
+    /// Returns true if [`Self::rasterize`] can produce a raster for the char. For chars that
+    /// the font doesn't have, the font would only return its "missing glyph" box. The codegen
+    /// must skip these chars.
+    pub fn has_glyph(&self, c: char) -> bool {
+        self.font_for_glyph(c).is_some() || self.rasterize_procedurally(c).is_some()
+    }
+
+    /// Returns the font that has a glyph for the char, preferring the main font over the
+    /// fallback font. The fallback font is only used for the unicode ranges that allow it.
+    /// See [`crate::unicode::uses_fallback_font`].
+    fn font_for_glyph(&self, c: char) -> Option<&Font> {
+        let fallback_font = self
+            .fallback_font
+            .as_ref()
+            .filter(|_| crate::unicode::uses_fallback_font(&self.fallback_ranges, c));
+        std::iter::once(&self.font)
+            .chain(fallback_font)
+            .find(|font| font.lookup_glyph_index(c) != 0)
+    }
+
+    /// Returns the raster of a char that is generated from the geometry of the raster
+    /// instead of the font, or `None`, if the char is taken from the font.
+    fn rasterize_procedurally(&self, c: char) -> Option<Vec<Vec<u8>>> {
+        crate::box_drawing::rasterize(self, c)
+            .or_else(|| crate::block_elements::rasterize(self, c))
+            .or_else(|| crate::braille::rasterize(self, c))
+            .or_else(|| crate::control_pictures::rasterize(self, c))
+    }
+
+    /// Rasterizes the glyph of the font for a char into a raster of the given width. See
+    /// [`Self::rasterize`].
+    fn rasterize_glyph(&self, c: char, raster_width: usize) -> Vec<Vec<u8>> {
+        let font = self.font_for_glyph(c).unwrap_or(&self.font);
+        let (mut metrics, mut fontdue_bitmap) = font.rasterize(c, self.font_size);
+        // The glyphs of the fallback font have other proportions. Shrink them if they are
+        // too wide instead of cutting them. Keep a pixel to the left and to the right, so that
+        // adjacent symbols, such as "★★★", don't touch each other.
+        let max_width = self.raster_width.saturating_sub(2).max(1);
+        if !std::ptr::eq(font, &self.font) && metrics.width > max_width {
+            let font_size = self.font_size * max_width as f32 / metrics.width as f32;
+            (metrics, fontdue_bitmap) = font.rasterize(c, font_size);
+        }
 
         // the bitmap that will contain the properly aligned rasterized char
-        let mut letter_bitmap = vec![vec![0_u8; self.raster_width]; self.raster_height];
+        let mut letter_bitmap = vec![vec![0_u8; raster_width]; self.raster_height];
 
         for ((y, x), intensity) in fontdue_bitmap
             .iter()
@@ -181,7 +347,7 @@ impl RasterizationInfo {
             .map(|(i, p)| (i as isize, p))
             .map(|(i, p)| {
                 // align to horizontal center
-                let x_offset = (self.raster_width as isize - metrics.width as isize) / 2;
+                let x_offset = (raster_width as isize - metrics.width as isize) / 2;
 
                 // align to vertical center
                 // 1) bounds:height: align big letters to groundline regarding the font size
@@ -190,7 +356,7 @@ impl RasterizationInfo {
                 y_offset -= metrics.ymin as isize;
                 // 3) move everything slightly to the top; I figured this out by trying with
                 //    my "rasterize_chars_in_window" binary
-                y_offset -= (self.raster_height as f32 * 0.07) as isize;
+                y_offset -= self.vertical_offset;
 
                 let x = i % metrics.width as isize;
                 let y = i as isize / metrics.width as isize;
@@ -199,7 +365,7 @@ impl RasterizationInfo {
                 let y = y + y_offset;
 
                 // if some letter is "too" big and out of bounds the box: cut and prevent error
-                let x = trim_index_to_bounds!(x, self.raster_width());
+                let x = trim_index_to_bounds!(x, raster_width);
                 let y = trim_index_to_bounds!(y, self.raster_height());
 
                 ((y, x), p)
@@ -211,16 +377,39 @@ impl RasterizationInfo {
         letter_bitmap
     }
 
+    /// Rasterizes a char of the main font into a bitmap of `width` x `height`. The glyph is
+    /// scaled down until it fits and then centered. This is used to compose procedurally
+    /// generated chars from small letters, such as the control pictures.
+    pub fn rasterize_scaled(&self, c: char, width: usize, height: usize) -> Vec<Vec<u8>> {
+        let mut font_size = self.font_size;
+        let (mut metrics, mut fontdue_bitmap) = self.font.rasterize(c, font_size);
+        while font_size > 1.0 && (metrics.width > width || metrics.height > height) {
+            font_size -= 0.5;
+            (metrics, fontdue_bitmap) = self.font.rasterize(c, font_size);
+        }
+        let mut bitmap = vec![vec![0_u8; width]; height];
+        let x_offset = width.saturating_sub(metrics.width) / 2;
+        let y_offset = height.saturating_sub(metrics.height) / 2;
+        for (i, intensity) in fontdue_bitmap.iter().enumerate() {
+            let x = x_offset + i % metrics.width;
+            let y = y_offset + i / metrics.width;
+            if x < width && y < height {
+                bitmap[y][x] = *intensity;
+            }
+        }
+        bitmap
+    }
+
     /// Searches the maximum width, that a pre-rasterized character/ will have for the given font
     /// size. This way, the width of the final raster can be reduced to HEIGHT x WIDTH instead of
     /// HEIGHT x HEIGHT, which would result a big space between all letters. The pre-rasterized
     /// characters will be all "monospaced" out-of-the box with this approach.
     ///
-    /// This function only takes ASCII letters (BASIC_LATIN) into account. Wider symbols are
-    /// ignored and will later be truncated/cut to this width.
+    /// This function only takes the printable ASCII chars into account. Wider symbols are
+    /// ignored and will later be truncated/cut to this width, unless they are so wide that
+    /// they occupy two cells, see [`Self::is_wide`].
     fn find_max_width(font: &Font, font_size: f32) -> (char, usize) {
-        let (char, max) = crate::unicode::BASIC_LATIN
-            .iter()
+        let (char, max) = (' '..='~')
             // return tuple of char and rasterized width
             .map(|c| (c, font.rasterize(c, font_size).0.width))
             // look for maximum by width
@@ -230,6 +419,25 @@ impl RasterizationInfo {
         (char, max + RASTERIZED_FONT_ADDITIONAL_PADDING)
     }
 
+    /// Returns the width of a vertical stem of the font in (fractional) pixels. This is the
+    /// coverage of the middle row of '|' and gets bigger with the font weight.
+    pub fn stem_width(&self) -> f32 {
+        let (metrics, bitmap) = self.font.rasterize('|', self.font_size);
+        let middle_row = metrics.height / 2 * metrics.width;
+        let coverage = bitmap[middle_row..middle_row + metrics.width]
+            .iter()
+            .map(|&intensity| intensity as u32)
+            .sum::<u32>();
+        coverage as f32 / u8::MAX as f32
+    }
+
+    /// Returns the number of rows above the baseline. The remaining rows of the raster are
+    /// below the baseline and hold descenders, such as in 'g' and 'y'.
+    pub const fn baseline(&self) -> usize {
+        // same vertical alignment as in `rasterize_glyph()` for a glyph without descender
+        (self.font_size as isize - self.vertical_offset) as usize
+    }
+
     pub const fn raster_height(&self) -> usize {
         self.raster_height
     }
@@ -247,17 +455,193 @@ impl RasterizationInfo {
     }
 }
 
+/// Returns the chars that occupy two cells, see [`RasterizationInfo::is_wide`].
+///
+/// This is decided once for the biggest raster height and all font weights, so that a char
+/// occupies the same number of cells for every raster height and font weight.
+pub fn find_wide_chars(config: &Config) -> BTreeSet<char> {
+    let height = config.raster_heights().last().unwrap().value() as usize;
+    config
+        .font_weights()
+        .iter()
+        .flat_map(|weight| {
+            let font = config.rasterization_info(weight, height);
+            config
+                .unicode_ranges()
+                .iter()
+                .flat_map(|range| range.iter())
+                .filter(|&char| font.has_glyph(char) && font.is_wide(char))
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use crate::config::test_config;
+
+    fn regular_font() -> &'static [u8] {
+        test_config().font_weight("regular").unwrap().font()
+    }
 
     #[test]
     fn test_font_props() {
-        let props = RasterizationInfo::new(16, NOTO_SANS_MONO_REGULAR);
+        let props = RasterizationInfo::new(16, regular_font());
         println!("raster_height = {}", props.raster_height());
         println!("font_size     = {}", props.font_size());
         println!("raster_width  = {}", props.raster_width());
         println!("widest_char   = '{}'", props.widest_char());
     }
+
+    #[test]
+    fn test_has_glyph() {
+        let props = RasterizationInfo::new(16, regular_font());
+        assert!(props.has_glyph('A'));
+        assert!(props.has_glyph('→'));
+        // generated procedurally
+        assert!(props.has_glyph('─'));
+        assert!(props.has_glyph('⣿'));
+        // not in Noto Sans Mono, only in the fallback font, see `test_fallback_font`
+        assert!(!props.has_glyph('⌘'));
+        assert!(!props.has_glyph('⏎'));
+        assert!(!props.has_glyph('✓'));
+    }
+
+    #[test]
+    fn test_wide_chars() {
+        let props = RasterizationInfo::new(16, regular_font());
+        assert!(props.is_wide('�'));
+        assert!(props.is_wide('⅓'));
+        assert!(!props.is_wide('W'));
+        assert!(!props.is_wide('Æ'));
+        // near the tolerance at the raster height that decides the wide chars: '⊕' is 1.2 and
+        // '※' is about 1.27 times as wide as the raster
+        let props = RasterizationInfo::new(64, regular_font());
+        assert!(!props.is_wide('⊕'));
+        assert!(props.is_wide('※'));
+        let props = RasterizationInfo::new(16, regular_font());
+        // procedurally generated
+        assert!(!props.is_wide('─'));
+        // zero advance, drawn over the previous char
+        assert!(!props.is_wide('\u{361}'));
+        let raster = props.rasterize_cells('�', 2);
+        assert_eq!(raster[0].len(), props.raster_width() * 2);
+        // no longer cut at the edges
+        assert!(raster
+            .iter()
+            .all(|row| row[0] == 0 && row[row.len() - 1] == 0));
+    }
+
+    #[test]
+    fn test_fallback_font() {
+        let config = test_config();
+        for weight in config.font_weights() {
+            let props = config.rasterization_info(weight, 16);
+            assert!(props.has_glyph('✓'));
+            assert!(props.has_glyph('⚠'));
+            assert!(props.has_glyph('⌘'));
+            assert!(props.has_glyph('⏎'));
+            // only for the unicode ranges with `fallback_font`, not for the arrows
+            assert!(!props.has_glyph('↵'));
+            // the glyph fits into the raster and is not cut at the borders
+            let raster = props.rasterize('★');
+            assert!(raster.iter().flatten().any(|&pixel| pixel > 0));
+            assert!(raster
+                .iter()
+                .all(|row| row[0] == 0 && row[row.len() - 1] == 0));
+        }
+    }
+
+    #[test]
+    fn test_baseline() {
+        for &raster_height in &[8, 16, 64] {
+            let props = RasterizationInfo::new(raster_height, regular_font());
+            let baseline = props.baseline();
+            assert!(baseline > raster_height / 2 && baseline < raster_height);
+            let last_ink_row = |c| {
+                props
+                    .rasterize(c)
+                    .iter()
+                    .rposition(|row| row.iter().any(|&p| p > 64))
+                    .unwrap()
+            };
+            assert_eq!(last_ink_row('H'), baseline - 1);
+            assert!(last_ink_row('g') >= baseline);
+        }
+    }
+
+    /// Returns the first and the last row of a char with clearly visible pixels, relative to the
+    /// top of the raster, with the same vertical alignment as in `rasterize()`. Faint
+    /// anti-aliasing may be cut.
+    fn visible_rows(props: &RasterizationInfo, c: char) -> Option<(isize, isize)> {
+        let (metrics, bitmap) = props.font.rasterize(c, props.font_size);
+        let y_offset = props.font_size as isize
+            - (metrics.height as isize + metrics.ymin as isize)
+            - props.vertical_offset;
+        let visible_rows = bitmap
+            .chunks(metrics.width.max(1))
+            .enumerate()
+            .filter(|(_, row)| row.iter().any(|&p| p > 64))
+            .map(|(y, _)| y as isize + y_offset);
+        Some((visible_rows.clone().min()?, visible_rows.max()?))
+    }
+
+    // Letters with diacritics above or descenders below must fit into the small and large
+    // rasters.
+    #[test]
+    fn test_small_and_large_raster_heights_not_clipped() {
+        let small: (&[usize], &[char]) = (&[8, 10, 12, 14], &['Ä', 'g', 'y']);
+        let large: (&[usize], &[char]) = (&[40, 48, 64], &['Ä', 'Å', 'g', 'y']);
+        for (raster_heights, chars) in [small, large] {
+            for (&raster_height, weight) in raster_heights
+                .iter()
+                .flat_map(|h| test_config().font_weights().iter().map(move |w| (h, w)))
+            {
+                let props = RasterizationInfo::new(raster_height, weight.font());
+                for &c in chars {
+                    let (top, bottom) = visible_rows(&props, c).unwrap();
+                    assert!(
+                        top >= 0 && bottom < raster_height as isize,
+                        "'{}' is clipped at raster height {} with weight {}",
+                        c,
+                        raster_height,
+                        weight.mod_name()
+                    );
+                }
+            }
+        }
+    }
+
+    // See `SMALL_FONT_SIZE_TO_RASTER_HEIGHT_RATIO`.
+    #[test]
+    fn test_latin_ranges_not_clipped_at_small_raster_heights() {
+        let config = test_config();
+        let ranges = [
+            "unicode-basic-latin",
+            "unicode-latin-1-supplement",
+            "unicode-latin-extended-a",
+        ]
+        .map(|name| config.unicode_range(name).unwrap());
+        for raster_height in [8, 10, 12, 14] {
+            for weight in config.font_weights() {
+                let props = RasterizationInfo::new(raster_height, weight.font());
+                for c in ranges.iter().flat_map(|range| range.iter()) {
+                    if props.font.lookup_glyph_index(c) == 0 {
+                        continue;
+                    }
+                    if let Some((top, bottom)) = visible_rows(&props, c) {
+                        assert!(
+                            top >= 0 && bottom < raster_height as isize,
+                            "'{}' is clipped at raster height {} with weight {}",
+                            c,
+                            raster_height,
+                            weight.mod_name()
+                        );
+                    }
+                }
+            }
+        }
+    }
 }
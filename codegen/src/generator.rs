@@ -0,0 +1,786 @@
+//! Generates the crate `noto-sans-mono-bitmap` from a [`Config`]: the Cargo.toml, the lib.rs,
+//! the modules of each font weight and raster height, and the files with the rasterized
+//! characters.
+//!
+//! All files are written through an [`OutputSink`] with paths relative to the root of the
+//! generated crate. Hence, the crate can be generated into any directory with a
+//! [`crate::output::DirectorySink`], or into memory with a [`crate::output::MemorySink`], e.g.,
+//! to build custom variants of the crate in another build pipeline.
+
+use crate::bytes_outsourcer::{BytesToFileOutsourcer, Context};
+use crate::config::Config;
+use crate::font::{find_wide_chars, FontWeight, RasterizationInfo};
+use crate::output::OutputSink;
+use crate::packed::pack_rasters;
+use crate::unicode::UnicodeRange;
+use crate::{
+    RasterHeight, RasterStorage, BRAILLE_RS, CARGO_LIB_RS, CARGO_TOML_TEMPLATE, CODEGEN_BASE_PATH,
+    CODEGEN_RASTERIZED_BYTES_PATH, COMBINING_RS, CONTROL_PICTURES_RS, GLYPH_SOURCE_RS, HEX_BOX_RS,
+    PSF_RS, RASTER_TABLE_RS, SIZE_MOD_TEMPLATE, WEIGHT_MOD_TEMPLATE,
+};
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+/// Debugging info about a generated crate, see [`generate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GenerateReport {
+    /// The widest char of the first font weight.
+    pub widest_char: char,
+    /// The chars that occupy two cells.
+    pub wide_chars: BTreeSet<char>,
+}
+
+/// Generates the whole crate for the configuration: Cargo.toml, lib.rs, the modules, and the
+/// rasterized characters. The paths of the files are relative to the root of the crate.
+pub fn generate(config: &Config, sink: &mut dyn OutputSink) -> GenerateReport {
+    // Shared instance for the font rasterization of all characters. Ensures unique filenames
+    // so that I can `include!` the Rust definitions for the rasterized characters.
+    let mut bytes_outsourcer = BytesToFileOutsourcer::new(CODEGEN_RASTERIZED_BYTES_PATH);
+
+    let widest_char = RasterizationInfo::new(20, config.font_weights()[0].font()).widest_char();
+    let wide_chars = find_wide_chars(config);
+
+    // create the font weight modules for each supported font weight.
+    for weight in config.font_weights() {
+        codegen_font_weight_module(sink, config, weight, &wide_chars, &mut bytes_outsourcer);
+    }
+
+    codegen_cargo_toml(config, sink);
+    codegen_lib_rs(config, sink);
+    codegen_static_module(sink, "raster_table.rs", RASTER_TABLE_RS);
+    codegen_static_module(sink, "braille.rs", BRAILLE_RS);
+    codegen_static_module(sink, "combining.rs", COMBINING_RS);
+    codegen_static_module(sink, "control_pictures.rs", CONTROL_PICTURES_RS);
+    codegen_static_module(sink, "hex_box.rs", HEX_BOX_RS);
+    codegen_static_module(sink, "glyph_source.rs", GLYPH_SOURCE_RS);
+    codegen_static_module(sink, "psf.rs", PSF_RS);
+
+    GenerateReport {
+        widest_char,
+        wide_chars,
+    }
+}
+
+/// Generates the Cargo.toml with all relevant features.
+fn codegen_cargo_toml(config: &Config, sink: &mut dyn OutputSink) {
+    let mut cargo_toml_file = String::new();
+
+    let mut features_font_weights = String::new();
+    config.font_weights().iter().for_each(|w| {
+        writeln!(&mut features_font_weights, "{} = []", w.mod_name()).unwrap();
+    });
+
+    let mut features_font_sizes = String::new();
+    config.raster_heights().iter().for_each(|size| {
+        writeln!(&mut features_font_sizes, "{} = []", size.feature_name()).unwrap();
+    });
+
+    let mut features_unicode_ranges = String::new();
+    config
+        .unicode_ranges()
+        .iter()
+        .map(|r| r.feature_name())
+        .for_each(|name| {
+            writeln!(&mut features_unicode_ranges, "{} = []", name).unwrap();
+        });
+
+    let mut features_font_styles_default = String::new();
+    writeln!(
+        &mut features_font_styles_default,
+        "font_weights_default = ["
+    )
+    .unwrap();
+    config
+        .font_weights()
+        .iter()
+        .filter(|x| x.default_feature())
+        .for_each(|w| {
+            writeln!(
+                &mut features_font_styles_default,
+                "    \"{}\",",
+                w.mod_name()
+            )
+            .unwrap();
+        });
+    writeln!(&mut features_font_styles_default, "]").unwrap();
+
+    let mut features_font_styles_all = String::new();
+    writeln!(&mut features_font_styles_all, "font_weights_all = [").unwrap();
+    config.font_weights().iter().for_each(|w| {
+        writeln!(&mut features_font_styles_all, "    \"{}\",", w.mod_name()).unwrap();
+    });
+    writeln!(&mut features_font_styles_all, "]").unwrap();
+
+    let mut features_unicode_default = String::new();
+    writeln!(&mut features_unicode_default, "unicode_ranges_default = [").unwrap();
+    config
+        .unicode_ranges()
+        .iter()
+        .filter(|r| r.default_feature)
+        .map(|r| r.feature_name())
+        .for_each(|name| {
+            writeln!(&mut features_unicode_default, "    \"{}\",", name).unwrap();
+        });
+    writeln!(&mut features_unicode_default, "]").unwrap();
+
+    let mut features_unicode_all = String::new();
+    writeln!(&mut features_unicode_all, "unicode_ranges_all = [").unwrap();
+    config
+        .unicode_ranges()
+        .iter()
+        .map(|r| r.feature_name())
+        .for_each(|name| {
+            writeln!(&mut features_unicode_all, "    \"{}\",", name).unwrap();
+        });
+    writeln!(&mut features_unicode_all, "]").unwrap();
+
+    let mut features_raster_heights_default = String::new();
+    writeln!(
+        &mut features_raster_heights_default,
+        "raster_heights_default = ["
+    )
+    .unwrap();
+    config
+        .raster_heights()
+        .iter()
+        .filter(|r| r.default_feature())
+        .for_each(|size| {
+            writeln!(
+                &mut features_raster_heights_default,
+                "    \"{}\",",
+                size.feature_name()
+            )
+            .unwrap();
+        });
+    writeln!(&mut features_raster_heights_default, "]").unwrap();
+
+    let mut features_raster_heights_all = String::new();
+    writeln!(&mut features_raster_heights_all, "raster_heights_all = [").unwrap();
+    config.raster_heights().iter().for_each(|size| {
+        writeln!(
+            &mut features_raster_heights_all,
+            "    \"{}\",",
+            size.feature_name()
+        )
+        .unwrap();
+    });
+    writeln!(&mut features_raster_heights_all, "]").unwrap();
+
+    // replace placeholders
+    #[rustfmt::skip]
+    writeln!(
+        &mut cargo_toml_file,
+        "{}",
+        CARGO_TOML_TEMPLATE
+            .replace("# %CODEGEN_FONT_WEIGHTS%", features_font_weights.as_str())
+            .replace("# %CODEGEN_FONT_SIZES%", features_font_sizes.as_str())
+            .replace("# %CODEGEN_UNICODE_RANGES%", features_unicode_ranges.as_str())
+            .replace("# %CODEGEN_FEATURES_RASTER_HEIGHTS_DEFAULT%", features_raster_heights_default.as_str())
+            .replace("# %CODEGEN_FEATURES_RASTER_HEIGHTS_ALL%", features_raster_heights_all.as_str())
+            .replace("# %CODEGEN_FEATURES_WEIGHTS_DEFAULT%", features_font_styles_default.as_str())
+            .replace("# %CODEGEN_FEATURES_WEIGHTS_ALL%", features_font_styles_all.as_str())
+            .replace("# %CODEGEN_FEATURES_UNICODE_RANGES_DEFAULT%", features_unicode_default.as_str())
+            .replace("# %CODEGEN_FEATURES_UNICODE_RANGES_ALL%", features_unicode_all.as_str())
+    )
+    .unwrap();
+    sink.write_file(Path::new("Cargo.toml"), cargo_toml_file.into_bytes());
+}
+
+/// Generates the lib.rs with all relevant features.
+fn codegen_lib_rs(config: &Config, sink: &mut dyn OutputSink) {
+    let mut lib_rs_path = PathBuf::from(CODEGEN_BASE_PATH);
+    lib_rs_path.push("lib.rs");
+
+    let mut lib_rs_file = String::new();
+
+    // codegen font weight modules
+    let mut weight_modules = String::new();
+    {
+        config.font_weights().iter().for_each(|w| {
+            writeln!(
+                &mut weight_modules,
+                "#[cfg(feature = \"{}\")]",
+                w.mod_name()
+            )
+            .unwrap();
+            writeln!(&mut weight_modules, "mod {};", w.mod_name()).unwrap();
+        });
+    }
+
+    // codegen font weight variants
+    let mut weight_variants = String::new();
+    {
+        config.font_weights().iter().for_each(|w| {
+            writeln!(
+                &mut weight_variants,
+                "    #[cfg(feature = \"{}\")]",
+                w.mod_name()
+            )
+            .unwrap();
+            // explicit discriminant, i.e., the position in the configuration, so that the value
+            // is independent of the selected features
+            writeln!(
+                &mut weight_variants,
+                "    {} = {},",
+                w.variant_name(),
+                w.discriminant()
+            )
+            .unwrap();
+        });
+    }
+
+    // codegen the entries of `FontWeight::ALL`
+    let mut weight_all = String::new();
+    {
+        config.font_weights().iter().for_each(|w| {
+            writeln!(
+                &mut weight_all,
+                "        #[cfg(feature = \"{}\")]",
+                w.mod_name()
+            )
+            .unwrap();
+            writeln!(&mut weight_all, "        Self::{},", w.variant_name()).unwrap();
+        });
+    }
+
+    // codegen the match arms of `TryFrom<usize> for FontWeight`
+    let mut weight_try_from = String::new();
+    {
+        config.font_weights().iter().for_each(|w| {
+            writeln!(
+                &mut weight_try_from,
+                "            #[cfg(feature = \"{}\")]",
+                w.mod_name()
+            )
+            .unwrap();
+            writeln!(
+                &mut weight_try_from,
+                "            {} => Ok(Self::{}),",
+                w.discriminant(),
+                w.variant_name()
+            )
+            .unwrap();
+        });
+    }
+
+    // codegen the entries of the test that compares `FontWeight::ALL` with the selected features
+    let mut weight_features = String::new();
+    {
+        config.font_weights().iter().for_each(|w| {
+            writeln!(
+                &mut weight_features,
+                "            ({}, cfg!(feature = \"{}\")),",
+                w.discriminant(),
+                w.mod_name()
+            )
+            .unwrap();
+        });
+    }
+
+    // codegen font size enum variants
+    let mut font_size_enum_variants = String::new();
+    {
+        config.raster_heights().iter().for_each(|height| {
+            writeln!(
+                &mut font_size_enum_variants,
+                "    #[cfg(feature = \"{}\")]",
+                height.feature_name()
+            )
+            .unwrap();
+            writeln!(
+                &mut font_size_enum_variants,
+                "    Size{} = {},",
+                height.value(),
+                height.value()
+            )
+            .unwrap();
+        });
+    }
+
+    // codegen the entries of `RasterHeight::ALL`
+    let mut raster_height_all = String::new();
+    {
+        config.raster_heights().iter().for_each(|height| {
+            writeln!(
+                &mut raster_height_all,
+                "        #[cfg(feature = \"{}\")]",
+                height.feature_name()
+            )
+            .unwrap();
+            writeln!(
+                &mut raster_height_all,
+                "        Self::Size{},",
+                height.value()
+            )
+            .unwrap();
+        });
+    }
+
+    // codegen the match arms of `TryFrom<usize> for RasterHeight`
+    let mut raster_height_try_from = String::new();
+    {
+        config.raster_heights().iter().for_each(|height| {
+            writeln!(
+                &mut raster_height_try_from,
+                "            #[cfg(feature = \"{}\")]",
+                height.feature_name()
+            )
+            .unwrap();
+            writeln!(
+                &mut raster_height_try_from,
+                "            {} => Ok(Self::Size{}),",
+                height.value(),
+                height.value()
+            )
+            .unwrap();
+        });
+    }
+
+    // codegen the entries of the test that compares `RasterHeight::ALL` with the selected features
+    let mut raster_height_features = String::new();
+    {
+        config.raster_heights().iter().for_each(|height| {
+            writeln!(
+                &mut raster_height_features,
+                "            ({}, cfg!(feature = \"{}\")),",
+                height.value(),
+                height.feature_name()
+            )
+            .unwrap();
+        });
+    }
+
+    // codegen get_raster match
+    let mut get_raster_match = String::new();
+    {
+        config.font_weights().iter().for_each(|w| {
+            writeln!(
+                &mut get_raster_match,
+                "        #[cfg(feature = \"{}\")]",
+                w.mod_name()
+            )
+            .unwrap();
+            writeln!(
+                &mut get_raster_match,
+                "        FontWeight::{} => match size {{",
+                w.variant_name()
+            )
+            .unwrap();
+            config.raster_heights().iter().for_each(|size| {
+                writeln!(
+                    &mut get_raster_match,
+                    "            #[cfg(feature = \"{}\")]",
+                    size.feature_name()
+                )
+                .unwrap();
+                writeln!(
+                    &mut get_raster_match,
+                    "            RasterHeight::Size{:?} => crate::{}::size_{}::get_char(c),",
+                    size.value(),
+                    w.mod_name(),
+                    size.value()
+                )
+                .unwrap();
+            });
+            writeln!(&mut get_raster_match, "        }}").unwrap();
+        });
+    }
+
+    // codegen get_raster_width match
+    let mut get_raster_width_match = String::new();
+    {
+        config.font_weights().iter().for_each(|w| {
+            writeln!(
+                &mut get_raster_width_match,
+                "        #[cfg(feature = \"{}\")]",
+                w.mod_name()
+            )
+            .unwrap();
+            writeln!(
+                &mut get_raster_width_match,
+                "        FontWeight::{} => match size {{",
+                w.variant_name()
+            )
+            .unwrap();
+            config.raster_heights().iter().for_each(|size| {
+                writeln!(
+                    &mut get_raster_width_match,
+                    "            #[cfg(feature = \"{}\")]",
+                    size.feature_name()
+                )
+                .unwrap();
+                writeln!(
+                    &mut get_raster_width_match,
+                    "            RasterHeight::Size{} => crate::{}::size_{}::RASTER_WIDTH,",
+                    size.value(),
+                    w.mod_name(),
+                    size.value()
+                )
+                .unwrap();
+            });
+            writeln!(&mut get_raster_width_match, "        }}").unwrap();
+        });
+    }
+
+    // replace placeholders
+    {
+        writeln!(
+            &mut lib_rs_file,
+            "{}",
+            CARGO_LIB_RS
+                .replace("// %CODEGEN_LIB_MODULES%", weight_modules.as_str())
+                .replace(
+                    "// %CODEGEN_FONT_WEIGHT_VARIANTS%",
+                    weight_variants.as_str()
+                )
+                .replace("// %CODEGEN_FONT_WEIGHT_ALL%", weight_all.as_str())
+                .replace(
+                    "// %CODEGEN_FONT_WEIGHT_TRY_FROM%",
+                    weight_try_from.trim_end()
+                )
+                .replace(
+                    "// %CODEGEN_RASTER_SIZE_ENUM_VARIANTS%",
+                    font_size_enum_variants.as_str()
+                )
+                .replace("// %CODEGEN_RASTER_HEIGHT_ALL%", raster_height_all.as_str())
+                .replace(
+                    "// %CODEGEN_RASTER_HEIGHT_TRY_FROM%",
+                    raster_height_try_from.trim_end()
+                )
+                .replace(
+                    "// %CODEGEN_FONT_WEIGHT_FEATURES%",
+                    weight_features.trim_end()
+                )
+                .replace(
+                    "// %CODEGEN_RASTER_HEIGHT_FEATURES%",
+                    raster_height_features.trim_end()
+                )
+                .replace("// %CODEGEN_get_raster%", get_raster_match.as_str())
+                .replace(
+                    "// %CODEGEN_get_raster_WIDTH%",
+                    get_raster_width_match.as_str()
+                )
+        )
+        .unwrap();
+    }
+    sink.write_file(&lib_rs_path, lib_rs_file.into_bytes());
+}
+
+/// Copies a module of the generated crate that doesn't depend on the codegen, such as the
+/// raster_table.rs, which looks up the packed rasters of a unicode range.
+fn codegen_static_module(sink: &mut dyn OutputSink, file_name: &str, content: &str) {
+    let mut module_path = PathBuf::from(CODEGEN_BASE_PATH);
+    module_path.push(file_name);
+    sink.write_file(&module_path, content.as_bytes().to_vec());
+}
+
+/// Creates a font weight module, like `bold/mod.rs`.
+fn codegen_font_weight_module(
+    sink: &mut dyn OutputSink,
+    config: &Config,
+    weight: &FontWeight,
+    wide_chars: &BTreeSet<char>,
+    outsourcer: &mut BytesToFileOutsourcer,
+) {
+    let mut mod_file_path = PathBuf::from(CODEGEN_BASE_PATH);
+    mod_file_path.push(weight.mod_name());
+    mod_file_path.push("mod.rs");
+
+    let mut mod_file = String::new();
+
+    // replace placeholders
+    writeln!(
+        &mut mod_file,
+        "{}",
+        WEIGHT_MOD_TEMPLATE.replace("%FONT_WEIGHT%", weight.mod_name())
+    )
+    .unwrap();
+
+    for height in config.raster_heights() {
+        let size = height.value();
+        // add font modules for the font sizes
+        writeln!(&mut mod_file, "#[cfg(feature = \"size_{}\")]", size).unwrap();
+        writeln!(&mut mod_file, "pub mod size_{};", size).unwrap();
+
+        let font = config.rasterization_info(weight, size as usize);
+        codegen_font_weight_sub_modules(
+            sink,
+            font,
+            config.unicode_ranges(),
+            height,
+            weight,
+            wide_chars,
+            outsourcer,
+        );
+    }
+    sink.write_file(&mod_file_path, mod_file.into_bytes());
+}
+
+/// Creates a `<weight>/size_<size>.rs` file and the files with the packed rasters of each unicode
+/// range. The module looks up the rasters of a char in a table for each unicode range.
+fn codegen_font_weight_sub_modules(
+    sink: &mut dyn OutputSink,
+    font: RasterizationInfo,
+    ranges: &[UnicodeRange],
+    height: &RasterHeight,
+    weight: &FontWeight,
+    wide_chars: &BTreeSet<char>,
+    outsourcer: &mut BytesToFileOutsourcer,
+) {
+    // the content of the file <weight>/size_<size>.rs
+    let mut size_mod_file = String::new();
+
+    // this block prepares the head of the just generated file
+    {
+        writeln!(
+            &mut size_mod_file,
+            "{}",
+            SIZE_MOD_TEMPLATE
+                .replace("%FONT_WEIGHT%", weight.mod_name())
+                .replace("%FONT_SIZE%", &format!("{}", font.raster_height()))
+                .replace(
+                    "%CODEGEN_RASTER_HEIGHT%",
+                    &format!("{}", font.raster_height())
+                )
+                .replace(
+                    "%CODEGEN_RASTER_WIDTH%",
+                    &format!("{}", font.raster_width())
+                )
+        )
+        .unwrap();
+    }
+
+    // the names of the generated tables with their unicode range
+    let mut tables = Vec::new();
+
+    // generates a table for the chars of each range, and another one for its wide chars, as
+    // they have a different width
+    for range in ranges {
+        let chars = range
+            .iter()
+            // skip chars that the font doesn't have; they would be the "missing glyph" box
+            .filter(|&char| font.has_glyph(char))
+            .collect::<Vec<_>>();
+        for wide in [false, true] {
+            let chars = chars
+                .iter()
+                .copied()
+                .filter(|char| wide_chars.contains(char) == wide)
+                .collect::<Vec<_>>();
+            if chars.is_empty() {
+                continue;
+            }
+            let cells = if wide { 2 } else { 1 };
+            let rasters = chars
+                .iter()
+                .map(|&char| font.rasterize_cells(char, cells))
+                .collect::<Vec<_>>();
+
+            // generate source code representation of the rasters or pack them
+            let raster_bytes = match height.storage() {
+                RasterStorage::Source => codegen_pixels(&rasters).into_bytes(),
+                RasterStorage::Packed => pack_rasters(&rasters),
+            };
+            let outsourced_path = outsourcer.outsource_bytes(
+                sink,
+                &raster_bytes,
+                Context {
+                    range,
+                    wide,
+                    weight,
+                    height: font.raster_height() as u32,
+                    storage: height.storage(),
+                },
+            );
+            // this is (as the rest of the codegen stuff) very ugly.
+            // need to adapt the path, so that cargo can find it during compilation..
+            let path = format!(
+                "../res_rasterized_characters/{}",
+                outsourced_path.file_name().unwrap().to_str().unwrap()
+            );
+
+            let table_name = format!(
+                "{}{}",
+                range.feature_name().to_uppercase().replace('-', "_"),
+                if wide { "_WIDE" } else { "" }
+            );
+            if wide {
+                writeln!(
+                    &mut size_mod_file,
+                    "/// The rasters of the wide chars of the unicode range `{}`, which span two \
+                    cells.",
+                    range.feature_name()
+                )
+                .unwrap();
+            } else {
+                writeln!(
+                    &mut size_mod_file,
+                    "/// The rasters of the unicode range `{}`.",
+                    range.feature_name()
+                )
+                .unwrap();
+            }
+            writeln!(
+                &mut size_mod_file,
+                "#[cfg(feature = \"{}\")]",
+                range.feature_name()
+            )
+            .unwrap();
+            writeln!(
+                &mut size_mod_file,
+                "static {}: RasterTable = RasterTable::new(",
+                table_name
+            )
+            .unwrap();
+            writeln!(&mut size_mod_file, "    &[{}],", codegen_chars(&chars)).unwrap();
+            match height.storage() {
+                RasterStorage::Source => {
+                    writeln!(&mut size_mod_file, "    &include!(\"{}\"),", path).unwrap();
+                }
+                RasterStorage::Packed => {
+                    writeln!(&mut size_mod_file, "    include_bytes!(\"{}\"),", path).unwrap();
+                }
+            }
+            writeln!(&mut size_mod_file, ");").unwrap();
+            writeln!(&mut size_mod_file).unwrap();
+            tables.push((table_name, range));
+        }
+    }
+
+    // generates the "get_char" function, which looks up the char in all tables
+    {
+        writeln!(
+            &mut size_mod_file,
+            "/// Returns the raster of the given character for font weight {} and font size {}px,\n\
+            /// row by row. Wide characters, such as '�', have a raster that spans two cells, i.e., it\n\
+            /// is twice as wide as [`RASTER_WIDTH`]. All characters are centered in their raster.",
+            weight.mod_name(),
+            font.font_size().round()
+        )
+        .unwrap();
+        writeln!(&mut size_mod_file, "#[inline]").unwrap();
+        writeln!(
+            &mut size_mod_file,
+            "pub fn get_char(c: char) -> Option<&'static [u8]> {{"
+        )
+        .unwrap();
+        writeln!(&mut size_mod_file, "    let tables: &[&RasterTable] = &[").unwrap();
+        for (table_name, range) in &tables {
+            writeln!(
+                &mut size_mod_file,
+                "        #[cfg(feature = \"{}\")]",
+                range.feature_name()
+            )
+            .unwrap();
+            writeln!(&mut size_mod_file, "        &{},", table_name).unwrap();
+        }
+        writeln!(&mut size_mod_file, "    ];").unwrap();
+        writeln!(
+            &mut size_mod_file,
+            "    tables.iter().find_map(|table| table.get(c))"
+        )
+        .unwrap();
+        writeln!(&mut size_mod_file, "}}").unwrap();
+    }
+
+    let mut mod_file_path = PathBuf::from(CODEGEN_BASE_PATH);
+    mod_file_path.push(weight.mod_name());
+    mod_file_path.push(format!("size_{}.rs", font.raster_height()));
+    sink.write_file(&mod_file_path, size_mod_file.into_bytes());
+}
+
+/// Generates the Rust source code of the chars of a table, separated by commas.
+fn codegen_chars(chars: &[char]) -> String {
+    chars
+        .iter()
+        .map(|&char| match char {
+            '\\' | '\'' => format!("'\\{}'", char),
+            char => format!("'{}'", char),
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Generates the Rust source code of type `[u8; N]` from rasters, with the same layout as
+/// [`pack_rasters`]. Each row of a raster is on a line of its own.
+fn codegen_pixels(rasters: &[Vec<Vec<u8>>]) -> String {
+    let mut rust_byte_array_str = String::new();
+    writeln!(&mut rust_byte_array_str, "[").unwrap();
+    for row in rasters.iter().flatten() {
+        write!(&mut rust_byte_array_str, "   ").unwrap();
+        for byte in row {
+            write!(&mut rust_byte_array_str, " {},", byte).unwrap();
+        }
+        writeln!(&mut rust_byte_array_str).unwrap();
+    }
+    write!(&mut rust_byte_array_str, "]").unwrap();
+    rust_byte_array_str
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::output::MemorySink;
+
+    #[test]
+    fn test_codegen_pixels() {
+        let a = [[1, 2, 3].to_vec(), [4, 5, 6].to_vec()].to_vec();
+        let b = [[7, 8, 9].to_vec(), [10, 11, 12].to_vec()].to_vec();
+        let generated_source_code = codegen_pixels(&[a, b]);
+        assert_eq!(
+            "[\n\
+            \x20\x20\x20\x201, 2, 3,\n\
+            \x20\x20\x20\x204, 5, 6,\n\
+            \x20\x20\x20\x207, 8, 9,\n\
+            \x20\x20\x20\x2010, 11, 12,\n\
+            ]",
+            &generated_source_code
+        );
+    }
+
+    #[test]
+    fn test_codegen_chars() {
+        assert_eq!(
+            codegen_chars(&['a', '\\', '\'', 'ä']),
+            "'a', '\\\\', '\\'', 'ä'"
+        );
+    }
+
+    #[test]
+    fn test_generate_variant() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(crate::CODEGEN_CONFIG_PATH);
+        let mut config = Config::load(path).unwrap();
+        config.retain_font_weights(&["bold"]).unwrap();
+        config.retain_raster_heights(&[8]).unwrap();
+        let mut sink = MemorySink::new();
+        let report = generate(&config, &mut sink);
+        assert!(!report.wide_chars.is_empty());
+
+        let file = |path: &str| String::from_utf8(sink.files()[Path::new(path)].clone()).unwrap();
+        let cargo_toml = file("Cargo.toml");
+        assert!(cargo_toml.contains("bold = []"));
+        assert!(cargo_toml.contains("size_8 = []"));
+        assert!(!cargo_toml.contains("regular = []"));
+        // the discriminant is the position in the configuration file, not the filtered one
+        let lib_rs = file("src/lib.rs");
+        assert!(lib_rs.contains("    Bold = 2,"));
+        assert!(lib_rs.contains("            2 => Ok(Self::Bold),"));
+        assert!(!lib_rs.contains("    Regular = "));
+        let size_8 = file("src/bold/size_8.rs");
+        assert!(size_8.contains("static UNICODE_BASIC_LATIN: RasterTable = RasterTable::new("));
+        assert!(size_8.contains(
+            "include_bytes!(\"../res_rasterized_characters/unicode-basic-latin_h8_wBold.bin\")"
+        ));
+        assert!(size_8.contains("static UNICODE_SPECIALS_WIDE: RasterTable"));
+        assert!(!size_8.contains("static UNICODE_SPECIALS: RasterTable"));
+        assert!(sink.files().contains_key(Path::new(
+            "src/res_rasterized_characters/unicode-basic-latin_h8_wBold.bin"
+        )));
+        assert!(!sink
+            .files()
+            .keys()
+            .any(|path| path.starts_with("src/regular")));
+    }
+}
@@ -0,0 +1,341 @@
+//! Golden images of all rasters, which detect unintended changes of glyphs, e.g., after
+//! regenerating the crate with `codegen_n_build.sh` or bumping fontdue.
+//!
+//! For each font weight, raster height, and unicode range, the rasters are laid out into one
+//! grayscale image, which is committed as `goldens/<weight>/size_<height>/<range>.png`. Each
+//! char of the range has a slot at the offset of its code point from the beginning of the
+//! range, in rows of [`COLUMNS`] slots. Slots are two cells wide, so that wide chars fit, and
+//! slots of chars that the crate doesn't have stay blank. Hence, adding a glyph only changes
+//! its own slot.
+//!
+//! `cargo test` renders the images again and compares each glyph pixel by pixel with its
+//! golden. Pixels differ if their intensities differ by more than the tolerance, which is
+//! [`DEFAULT_TOLERANCE`] or the value of the environment variable `GOLDEN_TOLERANCE`. For each
+//! image with differences, the test reports the differing pixels per glyph and writes a visual
+//! diff to `target/golden-diffs/`. After an intended change, update the goldens with
+//! `UPDATE_GOLDENS=1 cargo test --release golden`.
+
+use crate::font::RasterizationInfo;
+use crate::image::{Image, Rgb};
+use crate::unicode::UnicodeRange;
+use std::collections::BTreeSet;
+
+/// Number of slots in each row of a golden image.
+pub const COLUMNS: usize = 16;
+
+/// Maximum difference of the intensities of a pixel that is still considered equal. Absorbs
+/// rounding differences between platforms, e.g., from the SIMD code of fontdue.
+pub const DEFAULT_TOLERANCE: u8 = 2;
+
+/// Space between the panels of a visual diff.
+const DIFF_GAP: usize = 8;
+
+/// Position of the chars of a unicode range in its golden image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GoldenLayout {
+    /// First code point of the range.
+    pub begin: u32,
+    /// Number of code points in the range.
+    pub len: usize,
+    /// Width of a cell in pixels.
+    pub cell_width: usize,
+    /// Height of each raster in pixels.
+    pub height: usize,
+}
+
+impl GoldenLayout {
+    /// Layout of a unicode range for the raster size of the font.
+    pub const fn new(range: &UnicodeRange, font: &RasterizationInfo) -> Self {
+        Self {
+            begin: range.begin,
+            len: (range.end - range.begin + 1) as usize,
+            cell_width: font.raster_width(),
+            height: font.raster_height(),
+        }
+    }
+
+    /// Width of a slot, which are two cells.
+    pub const fn slot_width(&self) -> usize {
+        2 * self.cell_width
+    }
+
+    /// Width and height of the golden image.
+    pub const fn size(&self) -> (usize, usize) {
+        (
+            COLUMNS * self.slot_width(),
+            self.len.div_ceil(COLUMNS) * self.height,
+        )
+    }
+
+    /// Top left corner of the slot of a char of the range.
+    pub const fn slot(&self, c: char) -> (usize, usize) {
+        let index = (c as u32 - self.begin) as usize;
+        (
+            index % COLUMNS * self.slot_width(),
+            index / COLUMNS * self.height,
+        )
+    }
+
+    /// All chars of the range, including the ones that the crate doesn't have.
+    fn chars(&self) -> impl Iterator<Item = char> + '_ {
+        (self.begin..self.begin + self.len as u32).filter_map(char::from_u32)
+    }
+}
+
+/// Renders the golden image of a unicode range from the same rasters as the generated crate.
+pub fn render_golden(
+    font: &RasterizationInfo,
+    range: &UnicodeRange,
+    wide_chars: &BTreeSet<char>,
+) -> Vec<Vec<u8>> {
+    let layout = GoldenLayout::new(range, font);
+    let (width, height) = layout.size();
+    let mut image = vec![vec![0; width]; height];
+    for (c, _, raster) in font.rasterize_ranges(&[range], wide_chars) {
+        let (x, y) = layout.slot(c);
+        for (row_i, row) in raster.iter().enumerate() {
+            image[y + row_i][x..x + row.len()].copy_from_slice(row);
+        }
+    }
+    image
+}
+
+/// Pixels of a glyph that differ from its golden.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GlyphDelta {
+    pub c: char,
+    /// Number of pixels whose intensities differ by more than the tolerance.
+    pub pixels: usize,
+    /// Biggest difference of the intensities of a pixel.
+    pub max_delta: u8,
+}
+
+/// Compares each glyph of an image with its golden and returns the glyphs that differ. Pixels
+/// that only one of both images has, e.g., because the raster size changed, count as blank in
+/// the other image.
+pub fn compare_golden(
+    golden: &[Vec<u8>],
+    actual: &[Vec<u8>],
+    layout: &GoldenLayout,
+    tolerance: u8,
+) -> Vec<GlyphDelta> {
+    layout
+        .chars()
+        .filter_map(|c| {
+            let (x, y) = layout.slot(c);
+            let mut delta = GlyphDelta {
+                c,
+                pixels: 0,
+                max_delta: 0,
+            };
+            for y in y..y + layout.height {
+                for x in x..x + layout.slot_width() {
+                    let pixel_delta = intensity(golden, x, y).abs_diff(intensity(actual, x, y));
+                    if pixel_delta > tolerance {
+                        delta.pixels += 1;
+                        delta.max_delta = delta.max_delta.max(pixel_delta);
+                    }
+                }
+            }
+            (delta.pixels > 0).then_some(delta)
+        })
+        .collect()
+}
+
+/// Renders a visual diff with three panels side by side: the golden, the actual image, and
+/// the pixels that differ by more than the tolerance in red over a faint copy of the golden.
+pub fn visual_diff(golden: &[Vec<u8>], actual: &[Vec<u8>], tolerance: u8) -> Image {
+    let width = |image: &[Vec<u8>]| image.first().map_or(0, Vec::len);
+    let panel_width = width(golden).max(width(actual));
+    let panel_height = golden.len().max(actual.len());
+
+    let mut diff = vec![vec![0; panel_width]; panel_height];
+    for (y, row) in diff.iter_mut().enumerate() {
+        for (x, pixel) in row.iter_mut().enumerate() {
+            let delta = intensity(golden, x, y).abs_diff(intensity(actual, x, y));
+            *pixel = if delta > tolerance { 255 } else { 0 };
+        }
+    }
+
+    let mut image = Image::new(
+        3 * panel_width + 2 * DIFF_GAP,
+        panel_height,
+        [255, 255, 255],
+    );
+    let gap: Rgb = [200, 200, 200];
+    for i in 1..3 {
+        let x = i * (panel_width + DIFF_GAP) - DIFF_GAP;
+        image.fill_rect(x, 0, DIFF_GAP, panel_height, gap);
+    }
+    image.draw_raster(0, 0, golden, [0, 0, 0]);
+    image.draw_raster(panel_width + DIFF_GAP, 0, actual, [0, 0, 0]);
+    let x = 2 * (panel_width + DIFF_GAP);
+    image.draw_raster(x, 0, golden, [200, 200, 200]);
+    image.draw_raster(x, 0, &diff, [255, 0, 0]);
+    image
+}
+
+/// Intensity of a pixel, or 0, if the image doesn't have the pixel.
+fn intensity(image: &[Vec<u8>], x: usize, y: usize) -> u8 {
+    image
+        .get(y)
+        .and_then(|row| row.get(x))
+        .copied()
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::test_config;
+    use crate::font::find_wide_chars;
+    use crate::image::{decode_gray_png, encode_gray_png};
+    use std::fmt::Write;
+    use std::fs::create_dir_all;
+    use std::path::PathBuf;
+
+    const LAYOUT: GoldenLayout = GoldenLayout {
+        begin: 0x40,
+        len: 17,
+        cell_width: 1,
+        height: 2,
+    };
+
+    #[test]
+    fn test_layout() {
+        assert_eq!(LAYOUT.size(), (32, 4));
+        assert_eq!(LAYOUT.slot('@'), (0, 0));
+        assert_eq!(LAYOUT.slot('A'), (2, 0));
+        assert_eq!(LAYOUT.slot('P'), (0, 2));
+    }
+
+    #[test]
+    fn test_compare_golden() {
+        let golden = vec![vec![0; 32]; 4];
+        let mut actual = golden.clone();
+        actual[0][2] = 2; // 'A', within the tolerance
+        actual[1][3] = 100; // 'A'
+        actual[2][1] = 3; // 'P'
+        actual[1][4] = 255; // 'B'
+        actual[0][5] = 255; // 'B'
+        assert_eq!(
+            compare_golden(&golden, &actual, &LAYOUT, 2),
+            [
+                GlyphDelta {
+                    c: 'A',
+                    pixels: 1,
+                    max_delta: 100
+                },
+                GlyphDelta {
+                    c: 'B',
+                    pixels: 2,
+                    max_delta: 255
+                },
+                GlyphDelta {
+                    c: 'P',
+                    pixels: 1,
+                    max_delta: 3
+                },
+            ]
+        );
+        // missing rows count as blank
+        assert_eq!(compare_golden(&golden[..2], &golden, &LAYOUT, 0), []);
+    }
+
+    #[test]
+    fn test_visual_diff() {
+        let golden = vec![vec![0, 255]];
+        let actual = vec![vec![255, 255]];
+        let diff = visual_diff(&golden, &actual, 0);
+        assert_eq!((diff.width(), diff.height()), (6 + 2 * DIFF_GAP, 1));
+        assert_eq!(diff.pixel(0, 0), [255, 255, 255]);
+        assert_eq!(diff.pixel(2 + DIFF_GAP, 0), [0, 0, 0]);
+        let x = 4 + 2 * DIFF_GAP;
+        assert_eq!(diff.pixel(x, 0), [255, 0, 0]);
+        assert_eq!(diff.pixel(x + 1, 0), [200, 200, 200]);
+    }
+
+    /// Compares the rasters of every font weight, raster height, and unicode range with the
+    /// committed goldens. See the module description.
+    #[test]
+    fn test_goldens() {
+        let update = std::env::var_os("UPDATE_GOLDENS").is_some();
+        let tolerance = std::env::var("GOLDEN_TOLERANCE").map_or(DEFAULT_TOLERANCE, |value| {
+            value.parse().expect("GOLDEN_TOLERANCE must be 0 to 255")
+        });
+        let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        let diff_dir = manifest_dir.join("target/golden-diffs");
+        let config = test_config();
+        let wide_chars = find_wide_chars(config);
+
+        let mut failures = String::new();
+        for weight in config.font_weights() {
+            for height in config.raster_heights() {
+                let font = config.rasterization_info(weight, height.value() as usize);
+                let dir = manifest_dir
+                    .join("goldens")
+                    .join(weight.mod_name())
+                    .join(height.feature_name());
+                for range in config.unicode_ranges() {
+                    let path = dir.join(format!("{}.png", range.feature_name()));
+                    let actual = render_golden(&font, range, &wide_chars);
+                    if update {
+                        create_dir_all(&dir).unwrap();
+                        std::fs::write(&path, encode_gray_png(&actual)).unwrap();
+                        continue;
+                    }
+
+                    let golden = match std::fs::read(&path) {
+                        Ok(png) => decode_gray_png(&png).unwrap(),
+                        Err(_) => {
+                            writeln!(failures, "{}: missing", path.display()).unwrap();
+                            continue;
+                        }
+                    };
+                    let deltas = compare_golden(
+                        &golden,
+                        &actual,
+                        &GoldenLayout::new(range, &font),
+                        tolerance,
+                    );
+                    if deltas.is_empty() && golden.len() == actual.len() {
+                        continue;
+                    }
+
+                    let diff_path = diff_dir.join(format!(
+                        "{}-{}-{}.png",
+                        weight.mod_name(),
+                        height.value(),
+                        range.feature_name()
+                    ));
+                    create_dir_all(&diff_dir).unwrap();
+                    let diff = visual_diff(&golden, &actual, tolerance);
+                    std::fs::write(&diff_path, diff.encode_png()).unwrap();
+                    writeln!(
+                        failures,
+                        "{}: {} glyphs differ, see {}",
+                        path.display(),
+                        deltas.len(),
+                        diff_path.display()
+                    )
+                    .unwrap();
+                    for delta in deltas {
+                        writeln!(
+                            failures,
+                            "    U+{:04X} {:?}: {} pixels, max delta {}",
+                            delta.c as u32, delta.c, delta.pixels, delta.max_delta
+                        )
+                        .unwrap();
+                    }
+                }
+            }
+        }
+        assert!(
+            failures.is_empty(),
+            "rasters differ from the goldens by more than {}; if the change is intended, run \
+             `UPDATE_GOLDENS=1 cargo test --release golden`\n{}",
+            tolerance,
+            failures
+        );
+    }
+}
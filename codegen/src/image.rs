@@ -0,0 +1,173 @@
+//! Minimal RGB image for the specimen sheets, which can be written as PNG or PPM.
+//!
+//! This way, glyphs can be reviewed without a window, e.g., on CI machines. Rasters can also
+//! be stored as grayscale PNG, such as the golden images of [`crate::golden`].
+
+/// A color with red, green, and blue.
+pub type Rgb = [u8; 3];
+
+/// Image with 8-bit RGB pixels, row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    /// Creates an image that is filled with the background color.
+    pub fn new(width: usize, height: usize, background: Rgb) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![background; width * height],
+        }
+    }
+
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the color of a pixel.
+    pub fn pixel(&self, x: usize, y: usize) -> Rgb {
+        self.pixels[y * self.width + x]
+    }
+
+    /// Sets the color of a pixel. Pixels outside of the image are ignored.
+    pub fn set_pixel(&mut self, x: usize, y: usize, color: Rgb) {
+        if x < self.width && y < self.height {
+            self.pixels[y * self.width + x] = color;
+        }
+    }
+
+    /// Fills a rectangle with a color. Parts outside of the image are ignored.
+    pub fn fill_rect(&mut self, x: usize, y: usize, width: usize, height: usize, color: Rgb) {
+        for y in y..y + height {
+            for x in x..x + width {
+                self.set_pixel(x, y, color);
+            }
+        }
+    }
+
+    /// Draws a raster with its top left corner at (x, y). Each intensity blends the color
+    /// over the pixel, like the examples of the crate draw rasters into a framebuffer.
+    pub fn draw_raster(&mut self, x: usize, y: usize, raster: &[Vec<u8>], color: Rgb) {
+        for (row_i, row) in raster.iter().enumerate() {
+            for (col_i, &intensity) in row.iter().enumerate() {
+                let (x, y) = (x + col_i, y + row_i);
+                if x < self.width && y < self.height {
+                    let background = self.pixel(x, y);
+                    self.set_pixel(x, y, blend(background, color, intensity));
+                }
+            }
+        }
+    }
+
+    /// Encodes the image as binary PPM (P6), which needs no compression and can be read by
+    /// most image viewers and by a few lines of code.
+    pub fn encode_ppm(&self) -> Vec<u8> {
+        let mut ppm = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        ppm.extend(self.pixels.iter().flatten());
+        ppm
+    }
+
+    /// Encodes the image as PNG, which is much smaller than PPM and is shown inline by code
+    /// review tools.
+    pub fn encode_png(&self) -> Vec<u8> {
+        let mut png = Vec::new();
+        let mut encoder = png::Encoder::new(&mut png, self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder.set_compression(png::Compression::Best);
+        let mut writer = encoder.write_header().unwrap();
+        writer.write_image_data(&self.pixels.concat()).unwrap();
+        writer.finish().unwrap();
+        png
+    }
+}
+
+/// Encodes a raster, i.e., rows of intensities, as 8-bit grayscale PNG.
+pub fn encode_gray_png(raster: &[Vec<u8>]) -> Vec<u8> {
+    let width = raster.first().map_or(0, Vec::len);
+    let mut png = Vec::new();
+    let mut encoder = png::Encoder::new(&mut png, width as u32, raster.len() as u32);
+    encoder.set_color(png::ColorType::Grayscale);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder.set_compression(png::Compression::Best);
+    let mut writer = encoder.write_header().unwrap();
+    writer.write_image_data(&raster.concat()).unwrap();
+    writer.finish().unwrap();
+    png
+}
+
+/// Decodes an 8-bit grayscale PNG, as written by [`encode_gray_png`], into a raster.
+pub fn decode_gray_png(png: &[u8]) -> Result<Vec<Vec<u8>>, png::DecodingError> {
+    let mut reader = png::Decoder::new(png).read_info()?;
+    let mut pixels = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut pixels)?;
+    assert_eq!(
+        (info.color_type, info.bit_depth),
+        (png::ColorType::Grayscale, png::BitDepth::Eight),
+        "not an 8-bit grayscale PNG"
+    );
+    Ok(pixels[..info.buffer_size()]
+        .chunks_exact(info.line_size)
+        .map(<[u8]>::to_vec)
+        .collect())
+}
+
+/// Blends the color over the background with the intensity (0 to 255) as opacity.
+fn blend(background: Rgb, color: Rgb, intensity: u8) -> Rgb {
+    let alpha = intensity as u32;
+    let mut blended = [0; 3];
+    for ((blended, &background), &color) in blended.iter_mut().zip(&background).zip(&color) {
+        *blended = ((background as u32 * (255 - alpha) + color as u32 * alpha + 127) / 255) as u8;
+    }
+    blended
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_draw_raster() {
+        let mut image = Image::new(3, 2, [255; 3]);
+        image.draw_raster(1, 0, &[vec![255, 0], vec![128, 255]], [0, 0, 255]);
+        assert_eq!(image.pixel(0, 0), [255; 3]);
+        assert_eq!(image.pixel(1, 0), [0, 0, 255]);
+        assert_eq!(image.pixel(2, 0), [255; 3]);
+        assert_eq!(image.pixel(1, 1), [127, 127, 255]);
+        assert_eq!(image.pixel(2, 1), [0, 0, 255]);
+    }
+
+    #[test]
+    fn test_encode_ppm() {
+        let mut image = Image::new(2, 1, [0; 3]);
+        image.set_pixel(1, 0, [1, 2, 3]);
+        assert_eq!(image.encode_ppm(), b"P6\n2 1\n255\n\0\0\0\x01\x02\x03");
+    }
+
+    #[test]
+    fn test_gray_png() {
+        let raster = vec![vec![0, 1, 2], vec![253, 254, 255]];
+        assert_eq!(decode_gray_png(&encode_gray_png(&raster)).unwrap(), raster);
+    }
+
+    #[test]
+    fn test_encode_png() {
+        let image = Image::new(4, 4, [255, 0, 0]);
+        let png = image.encode_png();
+        assert!(png.starts_with(b"\x89PNG\r\n\x1a\n"));
+
+        let mut reader = png::Decoder::new(png.as_slice()).read_info().unwrap();
+        let mut pixels = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut pixels).unwrap();
+        assert_eq!((info.width, info.height), (4, 4));
+        assert_eq!(pixels, [255, 0, 0].repeat(16));
+    }
+}
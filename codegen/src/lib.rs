@@ -1,4 +1,7 @@
 //! Codegen utilities for the main codegen binary and examples/sub binaries in `src/bin`.
+//!
+//! [`generate`] generates the crate `noto-sans-mono-bitmap` for a [`config::Config`] into an
+//! [`output::OutputSink`], so that the codegen can also be used as a library.
 
 #![deny(
     clippy::all,
@@ -20,41 +23,54 @@
 #![deny(rustdoc::all)]
 #![allow(rustdoc::missing_doc_code_examples)]
 
-pub use crate::unicode::SUPPORTED_UNICODE_RANGES;
+pub use crate::generator::{generate, GenerateReport};
 
+use serde::Deserialize;
+
+pub mod bdf;
+pub mod bitmap_font;
+pub mod block_elements;
+pub mod box_drawing;
+pub mod braille;
 pub mod bytes_outsourcer;
+pub mod c_export;
+pub mod config;
+pub mod control_pictures;
 pub mod font;
+pub mod generator;
+pub mod golden;
+pub mod image;
+pub mod monochrome;
+pub mod output;
+pub mod packed;
+pub mod pcf;
+pub mod psf;
+pub mod specimen;
 pub mod unicode;
 
-// All supported raster heights that will be generated.
-pub const SUPPORTED_RASTER_HEIGHTS: &[RasterHeight] = &[
-    RasterHeight::new(16, true),
-    RasterHeight::new(20, false),
-    RasterHeight::new(24, false),
-    RasterHeight::new(32, false),
-    // Everything larger than this produces huge amounts of pre-rasterized definitions.
-    // It's unlikely that there is ever a valid use-case for this library where one needs
-    // such big pre-rasterized letters.
-];
-
 /// Teight of the rasterization process of certain characters. Like the font
 /// size but this describes the size of the outer box. Hence, the font size
 /// is a little smaller.
 ///
 /// The width of each character is influenced by this property. It will be a
 /// little less as well
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct RasterHeight {
     value: u32,
     // if the feature is included by default in Cargo.toml
+    #[serde(default)]
     default_feature: bool,
+    #[serde(default)]
+    storage: RasterStorage,
 }
 
 impl RasterHeight {
-    const fn new(value: u32, default_feature: bool) -> Self {
+    pub const fn new(value: u32, default_feature: bool, storage: RasterStorage) -> Self {
         Self {
             value,
             default_feature,
+            storage,
         }
     }
 
@@ -69,6 +85,27 @@ impl RasterHeight {
     pub fn default_feature(&self) -> bool {
         self.default_feature
     }
+
+    pub const fn storage(self) -> RasterStorage {
+        self.storage
+    }
+}
+
+/// Describes how the pre-rasterized characters of a [`RasterHeight`] are stored in the
+/// generated crate.
+///
+/// Either way, the rasters of each unicode range are packed into a single file (see
+/// [`packed`]), which the generated crate looks up in a table.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RasterStorage {
+    /// The packed rasters are stored as Rust source code that is `include!`d.
+    Source,
+    /// The packed rasters are stored as binary file that is `include_bytes!`d. This keeps the
+    /// download size of the crate small, as the crate package is compressed anyway. This is
+    /// the default.
+    #[default]
+    Packed,
 }
 
 /// Used as template to generate Rust modules for a specific font size.
@@ -79,14 +116,27 @@ pub const WEIGHT_MOD_TEMPLATE: &str = include_str!("codegen_templates/weight_mod
 pub const CARGO_TOML_TEMPLATE: &str = include_str!("codegen_templates/Cargo.toml.txt");
 /// Used as template to generate the lib.rs.
 pub const CARGO_LIB_RS: &str = include_str!("codegen_templates/lib.rs.template.txt");
+/// Module of the generated crate with the tables that look up the packed rasters.
+pub const RASTER_TABLE_RS: &str = include_str!("codegen_templates/raster_table.rs.txt");
+/// Module of the generated crate with helpers to draw dot graphics with braille patterns.
+pub const BRAILLE_RS: &str = include_str!("codegen_templates/braille.rs.txt");
+/// Module of the generated crate that draws combining marks over their base char.
+pub const COMBINING_RS: &str = include_str!("codegen_templates/combining.rs.txt");
+/// Module of the generated crate that makes control characters visible.
+pub const CONTROL_PICTURES_RS: &str = include_str!("codegen_templates/control_pictures.rs.txt");
+/// Module of the generated crate with fallback glyphs that show the code point of a char.
+pub const HEX_BOX_RS: &str = include_str!("codegen_templates/hex_box.rs.txt");
+/// Module of the generated crate that chains the rasters with other sources of glyphs.
+pub const GLYPH_SOURCE_RS: &str = include_str!("codegen_templates/glyph_source.rs.txt");
+/// Module of the generated crate that parses Linux console fonts (PSF).
+pub const PSF_RS: &str = include_str!("codegen_templates/psf.rs.txt");
 
+/// Path of the codegen configuration relative to the codegen, see [`config`].
+pub const CODEGEN_CONFIG_PATH: &str = "codegen.toml";
+/// Path of the main repository relative to the codegen, which is the default output directory
+/// of the codegen binary. All other paths are relative to it.
+pub const CODEGEN_REPOSITORY_PATH: &str = "..";
 /// Path into the main repository, where the codegen manipulates files.
-pub const CODEGEN_BASE_PATH: &str = "../src/";
+pub const CODEGEN_BASE_PATH: &str = "src/";
 /// Contains the rasterized bytes of all characters.
-pub const CODEGEN_RASTERIZED_BYTES_PATH: &str = "../src/res_rasterized_characters";
-
-/*#[cfg(test)]
-mod tests {
-    use super::*;
-
-}*/
+pub const CODEGEN_RASTERIZED_BYTES_PATH: &str = "src/res_rasterized_characters";
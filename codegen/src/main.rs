@@ -18,526 +18,315 @@
 #![deny(rustdoc::all)]
 #![allow(rustdoc::missing_doc_code_examples)]
 
-use codegen::bytes_outsourcer::{BytesToFileOutsourcer, Context};
-use codegen::font::{noto_font_by_weight, FontWeight, RasterizationInfo, SUPPORTED_FONT_WEIGHTS};
+use codegen::bdf::encode_bdf;
+use codegen::bitmap_font::BitmapFont;
+use codegen::c_export::{encode_c_header, encode_c_source, CFont};
+use codegen::config::{Config, ConfigError};
+use codegen::font::find_wide_chars;
+use codegen::monochrome::Monochrome;
+use codegen::output::{check_files, orphaned_files, DirectorySink, MemorySink};
+use codegen::pcf::encode_pcf;
+use codegen::psf::encode_psf2;
+use codegen::unicode::UnicodeRange;
 use codegen::{
-    CARGO_LIB_RS, CARGO_TOML_TEMPLATE, CODEGEN_BASE_PATH, CODEGEN_RASTERIZED_BYTES_PATH,
-    SIZE_MOD_TEMPLATE, SUPPORTED_RASTER_HEIGHTS, SUPPORTED_UNICODE_RANGES, WEIGHT_MOD_TEMPLATE,
+    generate, GenerateReport, CODEGEN_CONFIG_PATH, CODEGEN_RASTERIZED_BYTES_PATH,
+    CODEGEN_REPOSITORY_PATH,
 };
-use std::fmt::Write as FmtWrite;
-use std::fs::{create_dir, File};
-use std::io::Write as IoWrite;
-use std::path::PathBuf;
-
-/// Binary that does all the codegen.
+use std::ffi::OsStr;
+use std::fs::create_dir_all;
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+/// Binary that does all the codegen, see [`codegen::generate`]. It writes the crate into the
+/// main repository and removes raster files that no module references anymore.
+///
+/// * `--out-dir <dir>` writes the crate into `<dir>` instead of the main repository.
+/// * `--config <file>` reads the raster heights, font weights, and unicode ranges from `<file>`
+///   instead of `codegen.toml`, see [`codegen::config`]. An invalid configuration is reported
+///   before anything is generated.
+/// * `--only <weight/size,...>` only generates the given font weights and raster heights, such
+///   as `regular/16` or `regular/16,bold/24`. As the crate has every raster height for every
+///   font weight, all combinations of the given font weights and raster heights are generated.
+///   `*` selects all font weights or raster heights, such as `bold/*`. This also applies to the
+///   exports below. The wide chars are decided for the selected font weights and raster heights,
+///   see [`find_wide_chars`], hence a few rasters may differ from the full crate.
+///
+/// `--check` generates the crate in memory instead and compares it with the output directory.
+/// It lists missing, changed, and orphaned raster files and exits with 1, if there are any.
+///
+/// Instead of the crate, it can also export the rasters as bitmap fonts:
+/// * `--psf <dir>` writes a PSF2 font for each font weight and raster height into `<dir>`,
+///   without the wide chars, as all glyphs of a PSF font have the same size.
+/// * `--bdf <dir>` and `--pcf <dir>` write BDF and PCF fonts the same way.
+/// * `--c-source <dir>` writes a C header and source file with the rasters for each font weight
+///   and raster height into `<dir>`.
+/// * `--threshold <0-255>` sets the intensity from which pixels are set in 1-bit exports.
+/// * `--dither` dithers 1-bit exports instead of using a threshold.
+/// * `--ranges <feature,...>` only exports the given unicode ranges, such as
+///   `unicode-basic-latin,unicode-box-drawing`. The Linux console only loads fonts with up to
+///   512 glyphs.
 fn main() {
-    // Shared instance for the font rasterization of all characters. Ensures unique filenames
-    // so that I can `include!` the Rust definitions for the rasterized characters.
-    let mut bytes_outsourcer = BytesToFileOutsourcer::new(CODEGEN_RASTERIZED_BYTES_PATH);
-
-    // debugging info
-    {
-        let font =
-            RasterizationInfo::new(20 as usize, noto_font_by_weight(&SUPPORTED_FONT_WEIGHTS[0]));
-        println!("INFO: The widest char is '{}'", font.widest_char());
-    }
-
-    // create the font weight modules for each supported font weight.
-    for weight in SUPPORTED_FONT_WEIGHTS {
-        let font_bytes = noto_font_by_weight(weight);
-        codegen_font_weight_module(font_bytes, weight, &mut bytes_outsourcer);
-    }
-
-    codegen_cargo_toml();
-    codegen_lib_rs();
-}
-
-/// Generates the Cargo.toml with all relevant features.
-fn codegen_cargo_toml() {
-    // let mut path = PathBuf::from(CODEGEN_BASE_PATH);
-    let mut path = PathBuf::from("..");
-    path.push("Cargo.toml");
-
-    let mut cargo_toml_file = File::options()
-        .create(true)
-        .write(true)
-        .append(false)
-        .truncate(true)
-        .open(path)
-        .unwrap();
-
-    let mut features_font_weights = String::new();
-    SUPPORTED_FONT_WEIGHTS.iter().for_each(|w| {
-        writeln!(&mut features_font_weights, "{} = []", w.mod_name()).unwrap();
-    });
-
-    let mut features_font_sizes = String::new();
-    SUPPORTED_RASTER_HEIGHTS.iter().for_each(|size| {
-        writeln!(&mut features_font_sizes, "{} = []", size.feature_name()).unwrap();
-    });
-
-    let mut features_unicode_ranges = String::new();
-    SUPPORTED_UNICODE_RANGES
-        .iter()
-        .map(|r| r.feature_name)
-        .for_each(|name| {
-            writeln!(&mut features_unicode_ranges, "{} = []", name).unwrap();
-        });
-
-    let mut features_font_styles_default = String::new();
-    writeln!(
-        &mut features_font_styles_default,
-        "font_weights_default = ["
-    )
-    .unwrap();
-    SUPPORTED_FONT_WEIGHTS
-        .iter()
-        .filter(|x| x.default_feature())
-        .for_each(|w| {
-            writeln!(
-                &mut features_font_styles_default,
-                "    \"{}\",",
-                w.mod_name()
-            )
-            .unwrap();
-        });
-    writeln!(&mut features_font_styles_default, "]").unwrap();
-
-    let mut features_font_styles_all = String::new();
-    writeln!(&mut features_font_styles_all, "font_weights_all = [").unwrap();
-    SUPPORTED_FONT_WEIGHTS.iter().for_each(|w| {
-        writeln!(&mut features_font_styles_all, "    \"{}\",", w.mod_name()).unwrap();
-    });
-    writeln!(&mut features_font_styles_all, "]").unwrap();
-
-    let mut features_unicode_default = String::new();
-    writeln!(&mut features_unicode_default, "unicode_ranges_default = [").unwrap();
-    SUPPORTED_UNICODE_RANGES
-        .iter()
-        .filter(|r| r.default_feature)
-        .map(|r| r.feature_name)
-        .for_each(|name| {
-            writeln!(&mut features_unicode_default, "    \"{}\",", name).unwrap();
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    let config_path = option_value(&args, "--config").unwrap_or(CODEGEN_CONFIG_PATH);
+    let config = Config::load(config_path)
+        .and_then(|mut config| {
+            only_option(&mut config, &args)?;
+            Ok(config)
+        })
+        .unwrap_or_else(|e| {
+            println!("ERROR: {}", e);
+            std::process::exit(1);
         });
-    writeln!(&mut features_unicode_default, "]").unwrap();
-
-    let mut features_unicode_all = String::new();
-    writeln!(&mut features_unicode_all, "unicode_ranges_all = [").unwrap();
-    SUPPORTED_UNICODE_RANGES
+    let exports = BITMAP_FONT_FORMATS
         .iter()
-        .map(|r| r.feature_name)
-        .for_each(|name| {
-            writeln!(&mut features_unicode_all, "    \"{}\",", name).unwrap();
-        });
-    writeln!(&mut features_unicode_all, "]").unwrap();
-
-    let mut features_raster_heights_default = String::new();
-    writeln!(
-        &mut features_raster_heights_default,
-        "raster_heights_default = ["
-    )
-    .unwrap();
-    SUPPORTED_RASTER_HEIGHTS
-        .iter()
-        .filter(|r| r.default_feature())
-        .for_each(|size| {
-            writeln!(
-                &mut features_raster_heights_default,
-                "    \"{}\",",
-                size.feature_name()
-            )
-            .unwrap();
-        });
-    writeln!(&mut features_raster_heights_default, "]").unwrap();
-
-    let mut features_raster_heights_all = String::new();
-    writeln!(&mut features_raster_heights_all, "raster_heights_all = [").unwrap();
-    SUPPORTED_RASTER_HEIGHTS.iter().for_each(|size| {
-        writeln!(
-            &mut features_raster_heights_all,
-            "    \"{}\",",
-            size.feature_name()
-        )
-        .unwrap();
-    });
-    writeln!(&mut features_raster_heights_all, "]").unwrap();
-
-    // replace placeholders
-    #[rustfmt::skip]
-    writeln!(
-        &mut cargo_toml_file,
-        "{}",
-        CARGO_TOML_TEMPLATE
-            .replace("# %CODEGEN_FONT_WEIGHTS%", features_font_weights.as_str())
-            .replace("# %CODEGEN_FONT_SIZES%", features_font_sizes.as_str())
-            .replace("# %CODEGEN_UNICODE_RANGES%", features_unicode_ranges.as_str())
-            .replace("# %CODEGEN_FEATURES_RASTER_HEIGHTS_DEFAULT%", features_raster_heights_default.as_str())
-            .replace("# %CODEGEN_FEATURES_RASTER_HEIGHTS_ALL%", features_raster_heights_all.as_str())
-            .replace("# %CODEGEN_FEATURES_WEIGHTS_DEFAULT%", features_font_styles_default.as_str())
-            .replace("# %CODEGEN_FEATURES_WEIGHTS_ALL%", features_font_styles_all.as_str())
-            .replace("# %CODEGEN_FEATURES_UNICODE_RANGES_DEFAULT%", features_unicode_default.as_str())
-            .replace("# %CODEGEN_FEATURES_UNICODE_RANGES_ALL%", features_unicode_all.as_str())
-    )
-    .unwrap();
-}
-
-/// Generates the lib.rs with all relevant features.
-fn codegen_lib_rs() {
-    let mut lib_rs_path = PathBuf::from(CODEGEN_BASE_PATH);
-    lib_rs_path.push("lib.rs");
-
-    let mut cargo_toml_file = File::options()
-        .create(true)
-        .write(true)
-        .append(false)
-        .truncate(true)
-        .open(lib_rs_path)
-        .unwrap();
-
-    // codegen font weight modules
-    let mut weight_modules = String::new();
-    {
-        SUPPORTED_FONT_WEIGHTS.iter().for_each(|w| {
-            writeln!(&mut weight_modules, "mod {};", w.mod_name()).unwrap();
-        });
-    }
-
-    // codegen font weight variants
-    let mut weight_variants = String::new();
-    {
-        SUPPORTED_FONT_WEIGHTS.iter().for_each(|w| {
-            writeln!(
-                &mut weight_variants,
-                "    #[cfg(feature = \"{}\")]",
-                w.mod_name()
-            )
-            .unwrap();
-            writeln!(&mut weight_variants, "    {:?},", w.name()).unwrap();
-        });
-    }
-
-    // codegen font size enum variants
-    let mut font_size_enum_variants = String::new();
-    {
-        SUPPORTED_RASTER_HEIGHTS.iter().for_each(|height| {
-            writeln!(
-                &mut font_size_enum_variants,
-                "    #[cfg(feature = \"{}\")]",
-                height.feature_name()
-            )
-            .unwrap();
-            writeln!(
-                &mut font_size_enum_variants,
-                "    Size{} = {},",
-                height.value(),
-                height.value()
-            )
-            .unwrap();
-        });
+        .filter_map(|&(option, extension, encode)| {
+            option_value(&args, option).map(|out_dir| (Path::new(out_dir), extension, encode))
+        })
+        .collect::<Vec<_>>();
+    let c_source_dir = option_value(&args, "--c-source").map(Path::new);
+    if !exports.is_empty() || c_source_dir.is_some() {
+        let ranges = ranges_option(&config, &args);
+        if !exports.is_empty() {
+            export_bitmap_fonts(&config, &exports, &ranges, monochrome_option(&args));
+        }
+        if let Some(out_dir) = c_source_dir {
+            export_c_sources(&config, out_dir, &ranges);
+        }
+        return;
     }
 
-    // codegen get_raster match
-    let mut get_raster_match = String::new();
-    {
-        SUPPORTED_FONT_WEIGHTS.iter().for_each(|w| {
-            writeln!(
-                &mut get_raster_match,
-                "        #[cfg(feature = \"{}\")]",
-                w.mod_name()
-            )
-            .unwrap();
-            writeln!(
-                &mut get_raster_match,
-                "        FontWeight::{:?} => match size {{",
-                w.name()
-            )
-            .unwrap();
-            SUPPORTED_RASTER_HEIGHTS.iter().for_each(|size| {
-                writeln!(
-                    &mut get_raster_match,
-                    "            #[cfg(feature = \"{}\")]",
-                    size.feature_name()
-                )
-                .unwrap();
-                writeln!(
-                    &mut get_raster_match,
-                    "            RasterHeight::Size{:?} => crate::{}::size_{}::get_char(c),",
-                    size.value(),
-                    w.mod_name(),
-                    size.value()
-                )
-                .unwrap();
-            });
-            writeln!(&mut get_raster_match, "        }}").unwrap();
+    let repository = Path::new(option_value(&args, "--out-dir").unwrap_or(CODEGEN_REPOSITORY_PATH));
+    let raster_dir = Path::new(CODEGEN_RASTERIZED_BYTES_PATH);
+    if args.iter().any(|arg| arg == "--check") {
+        let mut sink = MemorySink::new();
+        print_generate_report(&generate(&config, &mut sink));
+        let report = check_files(repository, sink.files(), |path, content| {
+            // `codegen_n_build.sh` formats the generated crate after the codegen
+            if path.extension() == Some(OsStr::new("rs")) {
+                rustfmt(content)
+            } else {
+                content.to_vec()
+            }
         });
-    }
-
-    // codegen get_raster_width match
-    let mut get_raster_width_match = String::new();
-    {
-        SUPPORTED_FONT_WEIGHTS.iter().for_each(|w| {
-            writeln!(
-                &mut get_raster_width_match,
-                "        #[cfg(feature = \"{}\")]",
-                w.mod_name()
-            )
-            .unwrap();
-            writeln!(
-                &mut get_raster_width_match,
-                "        FontWeight::{:?} => match size {{",
-                w.name()
-            )
-            .unwrap();
-            SUPPORTED_RASTER_HEIGHTS.iter().for_each(|size| {
-                writeln!(
-                    &mut get_raster_width_match,
-                    "            #[cfg(feature = \"{}\")]",
-                    size.feature_name()
-                )
-                .unwrap();
-                writeln!(
-                    &mut get_raster_width_match,
-                    "            RasterHeight::Size{} => crate::{}::size_{}::RASTER_WIDTH,",
-                    size.value(),
-                    w.mod_name(),
-                    size.value()
-                )
-                .unwrap();
-            });
-            writeln!(&mut get_raster_width_match, "        }}").unwrap();
+        let orphaned = orphaned_files(repository, raster_dir, |path| {
+            sink.files().contains_key(path)
         });
+        for path in &report.missing {
+            println!("MISSING: {}", path.display());
+        }
+        for path in &report.changed {
+            println!("CHANGED: {}", path.display());
+        }
+        for path in &orphaned {
+            println!("ORPHANED: {}", path.display());
+        }
+        if !report.is_ok() || !orphaned.is_empty() {
+            println!(
+                "ERROR: the generated crate doesn't match the codegen; run `codegen_n_build.sh`"
+            );
+            std::process::exit(1);
+        }
+        println!("INFO: the generated crate matches the codegen");
+        return;
     }
 
-    // replace placeholders
-    {
-        writeln!(
-            &mut cargo_toml_file,
-            "{}",
-            CARGO_LIB_RS
-                .replace("// %CODEGEN_LIB_MODULES%", weight_modules.as_str())
-                .replace(
-                    "// %CODEGEN_FONT_WEIGHT_VARIANTS%",
-                    weight_variants.as_str()
-                )
-                .replace(
-                    "// %CODEGEN_RASTER_SIZE_ENUM_VARIANTS%",
-                    font_size_enum_variants.as_str()
-                )
-                .replace("// %CODEGEN_get_raster%", get_raster_match.as_str())
-                .replace(
-                    "// %CODEGEN_get_raster_WIDTH%",
-                    get_raster_width_match.as_str()
-                )
-        )
-        .unwrap();
+    let mut sink = DirectorySink::new(repository);
+    print_generate_report(&generate(&config, &mut sink));
+    for path in orphaned_files(repository, raster_dir, |path| sink.written().contains(path)) {
+        println!("INFO: removing orphaned raster file {}", path.display());
+        std::fs::remove_file(repository.join(path)).unwrap();
     }
 }
 
-/// Creates a font weight module, like `bold/mod.rs`.
-fn codegen_font_weight_module(
-    font_bytes: &[u8],
-    weight: &FontWeight,
-    outsourcer: &mut BytesToFileOutsourcer,
-) {
-    let mut mod_file_path = PathBuf::from(CODEGEN_BASE_PATH);
-    mod_file_path.push(weight.mod_name());
-    // ignore error; dir might exist
-    let _ = create_dir(&mod_file_path);
-    mod_file_path.push("mod.rs");
-
-    let mut mod_file = File::options()
-        .create(true)
-        .write(true)
-        .truncate(true)
-        .append(false)
-        .open(mod_file_path)
-        .unwrap();
-
-    // replace placeholders
-    writeln!(
-        &mut mod_file,
-        "{}",
-        WEIGHT_MOD_TEMPLATE.replace("%FONT_WEIGHT%", weight.mod_name())
-    )
-    .unwrap();
-
-    for size in SUPPORTED_RASTER_HEIGHTS.iter().map(|x| x.value()) {
-        // add font modules for the font sizes
-        writeln!(&mut mod_file, "#[cfg(feature = \"size_{}\")]", size).unwrap();
-        writeln!(&mut mod_file, "pub mod size_{};", size).unwrap();
+/// Prints the debugging info of the codegen.
+fn print_generate_report(report: &GenerateReport) {
+    println!("INFO: The widest char is '{}'", report.widest_char);
+    println!("INFO: {} chars occupy two cells", report.wide_chars.len());
+}
 
-        let font = RasterizationInfo::new(size as usize, font_bytes);
-        codegen_font_weight_sub_modules(font, weight, outsourcer);
-    }
+/// Formats Rust code with rustfmt, like `cargo fmt` formats the generated crate.
+fn rustfmt(source: &[u8]) -> Vec<u8> {
+    let mut rustfmt = Command::new("rustfmt")
+        .args(["--edition", "2018", "--emit", "stdout"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("rustfmt must be installed");
+    // write from another thread, so that rustfmt doesn't block on a full stdout pipe
+    let mut stdin = rustfmt.stdin.take().unwrap();
+    let source = source.to_vec();
+    let writer = std::thread::spawn(move || stdin.write_all(&source));
+    let output = rustfmt.wait_with_output().unwrap();
+    writer.join().unwrap().unwrap();
+    assert!(output.status.success(), "rustfmt failed");
+    output.stdout
 }
 
-/// Creates a `<weight>/size_<size>.rs` file performs all the code generation for the byte look-up of
-/// the pre-rasterized characters.
-fn codegen_font_weight_sub_modules(
-    font: RasterizationInfo,
-    weight: &FontWeight,
-    outsourcer: &mut BytesToFileOutsourcer,
-) {
-    // this block creates the file <weight>/size_<size>.rs and returns a File object to that file.
-    let mut size_mod_file = {
-        let mut mod_file_path = PathBuf::from(CODEGEN_BASE_PATH);
-        mod_file_path.push(weight.mod_name());
-        mod_file_path.push(format!("size_{}.rs", font.raster_height()));
+/// Returns the value that follows an option on the command line, such as `<dir>` of
+/// `--psf <dir>`.
+fn option_value<'a>(args: &'a [String], option: &str) -> Option<&'a str> {
+    let index = args.iter().position(|arg| arg == option)?;
+    let value = args
+        .get(index + 1)
+        .unwrap_or_else(|| panic!("{} requires a value", option));
+    Some(value.as_str())
+}
 
-        File::options()
-            .create(true)
-            .write(true)
-            .append(false)
-            .truncate(true)
-            .open(mod_file_path)
-            .unwrap()
+/// Restricts the configuration to the font weights and raster heights of
+/// `--only <weight/size,...>`. See [`main`].
+fn only_option(config: &mut Config, args: &[String]) -> Result<(), ConfigError> {
+    let selection = match option_value(args, "--only") {
+        None => return Ok(()),
+        Some(selection) => selection,
     };
-
-    // this block prepares the head of the just generated file
-    {
-        writeln!(
-            &mut size_mod_file,
-            "{}",
-            SIZE_MOD_TEMPLATE
-                .replace("%FONT_WEIGHT%", weight.mod_name())
-                .replace("%FONT_SIZE%", &format!("{}", font.raster_height()))
-                .replace(
-                    "%CODEGEN_RASTER_HEIGHT%",
-                    &format!("{}", font.raster_height())
-                )
-                .replace(
-                    "%CODEGEN_RASTER_WIDTH%",
-                    &format!("{}", font.raster_width())
-                )
-        )
-        .unwrap();
+    let (mut weights, mut heights) = (Some(Vec::new()), Some(Vec::new()));
+    for pair in selection.split(',') {
+        let (weight, height) = pair
+            .split_once('/')
+            .unwrap_or_else(|| panic!("--only expects <weight/size>, such as regular/16"));
+        match weight {
+            "*" => weights = None,
+            weight => weights.iter_mut().for_each(|weights| weights.push(weight)),
+        }
+        match height.trim_start_matches("size_") {
+            "*" => heights = None,
+            height => {
+                let height = height
+                    .parse::<u32>()
+                    .unwrap_or_else(|_| panic!("invalid raster height '{}'", height));
+                heights.iter_mut().for_each(|heights| heights.push(height));
+            }
+        }
     }
-
-    // the rest of the file generates the big match-block that maps characters to the pre-rasterized
-    // bytes.
-
-    // prepares the "get_char" function with its match block
-    let mut code_range_string = String::new();
-    {
-        writeln!(
-            &mut code_range_string,
-            "/// Returns the raster of the given character for font weight {} and font size {}px.\n\
-            /// Wide characters, such as '�', will be truncated in their width in order to fullfill\n\
-            /// the mono font guarantee. All characters are centered in their raster.",
-            weight.mod_name(),
-            font.font_size().round()
-        )
-        .unwrap();
-        writeln!(&mut code_range_string, "#[inline]").unwrap();
-        writeln!(
-            &mut code_range_string,
-            "pub const fn get_char(c: char) -> Option<&'static [&'static [u8]]> {{"
-        )
-        .unwrap();
-        writeln!(&mut code_range_string, "    match c {{").unwrap();
+    if let Some(weights) = weights {
+        config.retain_font_weights(&weights)?;
     }
+    if let Some(heights) = heights {
+        config.retain_raster_heights(&heights)?;
+    }
+    Ok(())
+}
 
-    // now we generate all the single match arms per character
-
-    // iterates through all ranges and for each range over all visible characters
-    SUPPORTED_UNICODE_RANGES.iter().for_each(|range| {
-        range
-            .iter()
-            .map(|char| (char, font.rasterize(char)))
-            .for_each(|(char, raster)| {
-                writeln!(
-                    &mut code_range_string,
-                    "        // letter: '{}' / {:?}",
-                    char, char as usize as *const usize
-                )
-                .unwrap();
-
-                // make this character optional by it's unicode range
-                writeln!(
-                    &mut code_range_string,
-                    "#[cfg(feature = \"{}\")]",
-                    range.feature_name
-                )
-                .unwrap();
-
-                // generate source code representation of raster
-                let rust_raster_source_code = codegen_raster(&raster);
-
-                let outsourced_path = outsourcer.outsource_bytes(
-                    rust_raster_source_code.as_bytes(),
-                    Context {
-                        c: char,
-                        weight: weight.clone(),
-                        height: font.raster_height() as u32,
-                    },
-                );
+/// Returns how 1-bit exports reduce the intensities, from `--dither` or `--threshold <0-255>`.
+fn monochrome_option(args: &[String]) -> Monochrome {
+    if args.iter().any(|arg| arg == "--dither") {
+        Monochrome::Dither
+    } else if let Some(threshold) = option_value(args, "--threshold") {
+        Monochrome::Threshold(threshold.parse().expect("threshold must be 0 to 255"))
+    } else {
+        Monochrome::default()
+    }
+}
 
-                // generate left side of the match arm
-                {
-                    if char == '\\' || char == '\'' {
-                        write!(&mut code_range_string, "        '\\{}'", char,).unwrap();
-                    } else {
-                        write!(&mut code_range_string, "        '{}'", char,).unwrap()
-                    }
-                }
-                // generate right side of the match arm
-                {
-                    // this is (as the rest of the codegen stuff) very ugly.
-                    // need to adapt the path, so that cargo can find it during compilation..
-                    let path = format!(
-                        "../res_rasterized_characters/{}",
-                        outsourced_path.file_name().unwrap().to_str().unwrap()
-                    );
-                    writeln!(&mut code_range_string, "=> Some(include!(\"{}\")),", path).unwrap();
-                }
+/// Returns the unicode ranges to export, from `--ranges <feature,...>`, or all unicode ranges
+/// of the configuration.
+fn ranges_option<'a>(config: &'a Config, args: &[String]) -> Vec<&'a UnicodeRange> {
+    match option_value(args, "--ranges") {
+        None => config.unicode_ranges().iter().collect(),
+        Some(names) => names
+            .split(',')
+            .map(|name| {
+                config
+                    .unicode_range(name)
+                    .unwrap_or_else(|| panic!("unknown unicode range '{}'", name))
             })
-    });
-    writeln!(&mut code_range_string, "        _ => None").unwrap();
-    // close match
-    writeln!(&mut code_range_string, "    }}").unwrap();
-    // close function
-    writeln!(&mut code_range_string, "}}").unwrap();
-
-    size_mod_file
-        .write_all(code_range_string.as_bytes())
-        .unwrap();
+            .collect(),
+    }
 }
 
-/// Generates the Rust source code of type `&[&u8]]` from a raster.
-fn codegen_raster(raster: &Vec<Vec<u8>>) -> String {
-    let mut rust_byte_array_str = String::new();
-    writeln!(&mut rust_byte_array_str, "&[").unwrap();
-    for row in raster {
-        write!(&mut rust_byte_array_str, "    &[").unwrap();
-        for (i, byte) in row.iter().enumerate() {
-            if i == row.len() - 1 {
-                write!(&mut rust_byte_array_str, "{}", byte).unwrap();
-            } else {
-                write!(&mut rust_byte_array_str, "{}, ", byte).unwrap();
+/// Encodes a bitmap font into the bytes of a file.
+type BitmapFontEncoder = fn(&BitmapFont) -> Vec<u8>;
+
+/// Bitmap font formats that the codegen exports instead of the crate: the command line option,
+/// the file extension, and the encoder.
+const BITMAP_FONT_FORMATS: [(&str, &str, BitmapFontEncoder); 3] = [
+    ("--psf", "psf", encode_psf2),
+    ("--bdf", "bdf", |font| encode_bdf(font).into_bytes()),
+    ("--pcf", "pcf", encode_pcf),
+];
+
+/// Copyright notice of the exported bitmap fonts.
+const BITMAP_FONT_NOTICE: &str = "Rasterized from Noto Sans Mono (SIL Open Font License 1.1) and, \
+    for a few symbols, DejaVu Sans Mono (Bitstream Vera License)";
+
+/// Writes a bitmap font for each supported font weight and raster height in each of the given
+/// formats into their output directory. The fonts contain all chars of the given unicode ranges,
+/// including the wide chars that occupy two cells, unless the format doesn't support them.
+fn export_bitmap_fonts(
+    config: &Config,
+    exports: &[(&Path, &str, BitmapFontEncoder)],
+    ranges: &[&UnicodeRange],
+    monochrome: Monochrome,
+) {
+    for (out_dir, _, _) in exports {
+        create_dir_all(out_dir).unwrap();
+    }
+    let wide_chars = find_wide_chars(config);
+    for weight in config.font_weights() {
+        for height in config.raster_heights() {
+            let font = config.rasterization_info(weight, height.value() as usize);
+            let bitmap_font = BitmapFont {
+                family_name: "Noto Sans Mono".to_string(),
+                weight_name: weight.variant_name(),
+                width: font.raster_width(),
+                height: font.raster_height(),
+                ascent: font.baseline(),
+                notice: BITMAP_FONT_NOTICE.to_string(),
+                glyphs: font
+                    .rasterize_ranges(ranges, &wide_chars)
+                    .into_iter()
+                    .map(|(char, cells, raster)| (char, cells, monochrome.apply(&raster)))
+                    .collect(),
+            };
+
+            for (out_dir, extension, encode) in exports {
+                let path = out_dir.join(format!(
+                    "noto-sans-mono-{}-{}.{}",
+                    weight.mod_name(),
+                    height.value(),
+                    extension
+                ));
+                std::fs::write(&path, encode(&bitmap_font)).unwrap();
+                println!("INFO: wrote {}", path.display());
             }
         }
-        writeln!(&mut rust_byte_array_str, "],").unwrap();
     }
-    write!(&mut rust_byte_array_str, "]").unwrap();
-    rust_byte_array_str
 }
 
-#[cfg(test)]
-mod tests {
-
-    use super::*;
-
-    #[test]
-    fn test_codegen_raster() {
-        let raster = [[1, 2, 3].to_vec(), [4, 5, 6].to_vec(), [7, 8, 9].to_vec()].to_vec();
-        let generated_source_code = codegen_raster(&raster);
-        assert_eq!(
-            "&[\n\
-            \x20\x20\x20\x20&[1, 2, 3],\n\
-            \x20\x20\x20\x20&[4, 5, 6],\n\
-            \x20\x20\x20\x20&[7, 8, 9],\n\
-            ]",
-            &generated_source_code
-        );
+/// Writes a C header and source file for each supported font weight and raster height into the
+/// output directory. They contain the same rasters as the Rust modules for all chars of the given
+/// unicode ranges, including the wide chars that occupy two cells.
+fn export_c_sources(config: &Config, out_dir: &Path, ranges: &[&UnicodeRange]) {
+    create_dir_all(out_dir).unwrap();
+    let wide_chars = find_wide_chars(config);
+    for weight in config.font_weights() {
+        for height in config.raster_heights() {
+            let font = config.rasterization_info(weight, height.value() as usize);
+            let c_font = CFont {
+                prefix: format!("noto_sans_mono_{}_{}", weight.mod_name(), height.value()),
+                notice: format!(
+                    "Noto Sans Mono, font weight {}, raster height {}px.\n{}.",
+                    weight.mod_name(),
+                    height.value(),
+                    BITMAP_FONT_NOTICE
+                ),
+                width: font.raster_width(),
+                height: font.raster_height(),
+                glyphs: font.rasterize_ranges(ranges, &wide_chars),
+            };
+
+            for (name, content) in [
+                (c_font.header_name(), encode_c_header(&c_font)),
+                (c_font.source_name(), encode_c_source(&c_font)),
+            ] {
+                std::fs::write(out_dir.join(name), content).unwrap();
+            }
+            println!(
+                "INFO: wrote {} chars to {}",
+                c_font.glyphs.len(),
+                out_dir.join(c_font.source_name()).display()
+            );
+        }
     }
 }
@@ -0,0 +1,113 @@
+//! Conversion of anti-aliased rasters to one bit per pixel, for bitmap font formats such as PSF.
+
+/// How the intensities of a raster are reduced to set or unset pixels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Monochrome {
+    /// Pixels with at least this intensity are set.
+    Threshold(u8),
+    /// Floyd-Steinberg dithering. Keeps more of the shape of thin and round strokes, but looks
+    /// noisier than a threshold.
+    Dither,
+}
+
+impl Default for Monochrome {
+    fn default() -> Self {
+        Self::Threshold(128)
+    }
+}
+
+impl Monochrome {
+    /// Converts a raster. Each row of the result has as many pixels as the row of the raster.
+    pub fn apply(self, raster: &[Vec<u8>]) -> Vec<Vec<bool>> {
+        match self {
+            Self::Threshold(threshold) => raster
+                .iter()
+                .map(|row| row.iter().map(|&pixel| pixel >= threshold).collect())
+                .collect(),
+            Self::Dither => dither(raster),
+        }
+    }
+}
+
+/// Packs a row of pixels into bytes, the most significant bit first. The last byte is padded
+/// with zero bits.
+pub fn pack_row(row: &[bool]) -> Vec<u8> {
+    let mut bytes = vec![0; row.len().div_ceil(8)];
+    for (x, _) in row.iter().enumerate().filter(|(_, &set)| set) {
+        bytes[x / 8] |= 0x80 >> (x % 8);
+    }
+    bytes
+}
+
+/// Floyd-Steinberg dithering: the error of each pixel is distributed to its neighbours to the
+/// right and below.
+fn dither(raster: &[Vec<u8>]) -> Vec<Vec<bool>> {
+    let mut intensities = raster
+        .iter()
+        .map(|row| row.iter().map(|&pixel| pixel as i32).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    let mut bits = Vec::with_capacity(raster.len());
+    for y in 0..intensities.len() {
+        let width = intensities[y].len();
+        let mut row = Vec::with_capacity(width);
+        for x in 0..width {
+            let old = intensities[y][x];
+            let set = old >= 128;
+            let error = old - if set { 255 } else { 0 };
+            let mut spread = |x: Option<usize>, y: usize, weight: i32| {
+                if let Some(pixel) = x.and_then(|x| intensities.get_mut(y)?.get_mut(x)) {
+                    *pixel += error * weight / 16;
+                }
+            };
+            spread(Some(x + 1), y, 7);
+            spread(x.checked_sub(1), y + 1, 3);
+            spread(Some(x), y + 1, 5);
+            spread(Some(x + 1), y + 1, 1);
+            row.push(set);
+        }
+        bits.push(row);
+    }
+    bits
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_threshold() {
+        let raster = [vec![0, 127, 128, 255]];
+        assert_eq!(
+            Monochrome::default().apply(&raster),
+            [[false, false, true, true]]
+        );
+        assert_eq!(
+            Monochrome::Threshold(1).apply(&raster),
+            [[false, true, true, true]]
+        );
+    }
+
+    #[test]
+    fn test_pack_row() {
+        assert_eq!(pack_row(&[true, false, true]), [0b1010_0000]);
+        let mut row = vec![false; 9];
+        row[8] = true;
+        assert_eq!(pack_row(&row), [0, 0x80]);
+    }
+
+    #[test]
+    fn test_dither() {
+        assert_eq!(
+            Monochrome::Dither.apply(&vec![vec![0; 4]; 4]),
+            [[false; 4]; 4]
+        );
+        assert_eq!(
+            Monochrome::Dither.apply(&vec![vec![255; 4]; 4]),
+            [[true; 4]; 4]
+        );
+        // a mid grey becomes a pattern with half of the pixels set
+        let grey = Monochrome::Dither.apply(&vec![vec![128; 8]; 8]);
+        let set = grey.iter().flatten().filter(|&&set| set).count();
+        assert!((28..=36).contains(&set), "{} pixels set", set);
+    }
+}
@@ -0,0 +1,173 @@
+//! Destinations of the files of the generated crate.
+//!
+//! The codegen writes all files through an [`OutputSink`], with paths relative to the root of
+//! the repository, such as `src/lib.rs`. [`DirectorySink`] writes them to disk. [`MemorySink`]
+//! keeps them in memory, so that `--check` can compare them with the working tree, see
+//! [`check_files`].
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs::{create_dir_all, read_dir};
+use std::path::{Path, PathBuf};
+
+/// Destination of the generated files.
+pub trait OutputSink {
+    /// Writes a generated file. The path is relative to the root of the repository.
+    fn write_file(&mut self, path: &Path, content: Vec<u8>);
+}
+
+/// Writes the files into a directory, usually the root of the repository, and remembers
+/// their paths.
+#[derive(Debug)]
+pub struct DirectorySink {
+    root: PathBuf,
+    written: BTreeSet<PathBuf>,
+}
+
+impl DirectorySink {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self {
+            root: root.into(),
+            written: BTreeSet::new(),
+        }
+    }
+
+    /// The paths of all files that were written, relative to the root.
+    pub const fn written(&self) -> &BTreeSet<PathBuf> {
+        &self.written
+    }
+}
+
+impl OutputSink for DirectorySink {
+    fn write_file(&mut self, path: &Path, content: Vec<u8>) {
+        let full_path = self.root.join(path);
+        if let Some(parent) = full_path.parent() {
+            create_dir_all(parent).unwrap();
+        }
+        std::fs::write(&full_path, content)
+            .unwrap_or_else(|e| panic!("can't write {}: {}", full_path.display(), e));
+        self.written.insert(path.to_path_buf());
+    }
+}
+
+/// Keeps the files in memory.
+#[derive(Debug, Default)]
+pub struct MemorySink {
+    files: BTreeMap<PathBuf, Vec<u8>>,
+}
+
+impl MemorySink {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The files by their path relative to the root.
+    pub const fn files(&self) -> &BTreeMap<PathBuf, Vec<u8>> {
+        &self.files
+    }
+}
+
+impl OutputSink for MemorySink {
+    fn write_file(&mut self, path: &Path, content: Vec<u8>) {
+        self.files.insert(path.to_path_buf(), content);
+    }
+}
+
+/// Returns the files in the directory `dir` below `root` that are not generated, e.g., stale
+/// rasters of chars that the crate no longer has. The paths are relative to `root` and sorted.
+pub fn orphaned_files(
+    root: &Path,
+    dir: &Path,
+    is_generated: impl Fn(&Path) -> bool,
+) -> Vec<PathBuf> {
+    let mut orphaned = read_dir(root.join(dir))
+        .map(|entries| {
+            entries
+                .map(|entry| dir.join(entry.unwrap().file_name()))
+                .filter(|path| root.join(path).is_file() && !is_generated(path))
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    orphaned.sort();
+    orphaned
+}
+
+/// Differences between the generated files and the files below a root directory.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct CheckReport {
+    /// Generated files that don't exist.
+    pub missing: Vec<PathBuf>,
+    /// Generated files whose content differs.
+    pub changed: Vec<PathBuf>,
+}
+
+impl CheckReport {
+    /// Returns true if all generated files exist with the same content.
+    pub const fn is_ok(&self) -> bool {
+        self.missing.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Compares the generated files with the files below `root`. Each generated file is passed
+/// through `normalize` first, e.g., to format Rust code like `cargo fmt` does after the
+/// codegen.
+pub fn check_files(
+    root: &Path,
+    files: &BTreeMap<PathBuf, Vec<u8>>,
+    normalize: impl Fn(&Path, &[u8]) -> Vec<u8>,
+) -> CheckReport {
+    let mut report = CheckReport::default();
+    for (path, content) in files {
+        match std::fs::read(root.join(path)) {
+            Err(_) => report.missing.push(path.clone()),
+            Ok(existing) if existing != normalize(path, content) => {
+                report.changed.push(path.clone())
+            }
+            Ok(_) => {}
+        }
+    }
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_files() {
+        let root = PathBuf::from("target/test_check_files");
+        let _ = std::fs::remove_dir_all(&root);
+        let mut disk = DirectorySink::new(&root);
+        disk.write_file(Path::new("res/same.txt"), b"same".to_vec());
+        disk.write_file(Path::new("res/changed.txt"), b"old".to_vec());
+        disk.write_file(Path::new("res/orphaned.txt"), b"orphaned".to_vec());
+
+        let mut memory = MemorySink::new();
+        memory.write_file(Path::new("res/same.txt"), b"SAME".to_vec());
+        memory.write_file(Path::new("res/changed.txt"), b"new".to_vec());
+        memory.write_file(Path::new("res/missing.txt"), b"missing".to_vec());
+
+        let report = check_files(&root, memory.files(), |path, content| {
+            // only the first file needs normalization to match
+            if path.ends_with("same.txt") {
+                content.to_ascii_lowercase()
+            } else {
+                content.to_vec()
+            }
+        });
+        assert_eq!(
+            report,
+            CheckReport {
+                missing: vec![PathBuf::from("res/missing.txt")],
+                changed: vec![PathBuf::from("res/changed.txt")],
+            }
+        );
+        assert!(!report.is_ok());
+        assert_eq!(
+            orphaned_files(&root, Path::new("res"), |path| memory
+                .files()
+                .contains_key(path)),
+            [PathBuf::from("res/orphaned.txt")]
+        );
+        assert_eq!(disk.written().len(), 3);
+    }
+}
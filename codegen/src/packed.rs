@@ -0,0 +1,50 @@
+//! Packing of the rasters of a unicode range into a single file, see [`crate::RasterStorage`].
+//!
+//! A packed file contains the rasters of all chars of a unicode range that have the same width,
+//! one after another. Each raster is stored row by row with one byte per pixel, without any
+//! header or padding. Hence, the generated crate finds the raster of the n-th char of the range
+//! at the offset `n * width * height` and uses the bytes as they are. Nothing has to be decoded
+//! during the compilation of the generated crate, see `codegen_templates/raster_table.rs.txt`.
+
+/// Packs rasters of the same width and height into the format described in the module
+/// description.
+pub fn pack_rasters(rasters: &[Vec<Vec<u8>>]) -> Vec<u8> {
+    rasters.iter().flatten().flatten().copied().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Same layout as the lookup in the generated crate.
+    fn unpack_raster(packed: &[u8], width: usize, height: usize, index: usize) -> Vec<Vec<u8>> {
+        packed[index * width * height..(index + 1) * width * height]
+            .chunks(width)
+            .map(|row| row.to_vec())
+            .collect()
+    }
+
+    #[test]
+    fn test_pack_rasters() {
+        let a = [[0, 0, 3].to_vec(), [4, 0, 0].to_vec()].to_vec();
+        let b = [[5, 6, 7].to_vec(), [0, 0, 8].to_vec()].to_vec();
+        assert_eq!(pack_rasters(&[a, b]), [0, 0, 3, 4, 0, 0, 5, 6, 7, 0, 0, 8]);
+        assert!(pack_rasters(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_pack_rasters_roundtrip() {
+        let rasters = (0..5)
+            .map(|i| {
+                (0..64)
+                    .map(|y| (0..35).map(|x| ((x * y + i) % 7 * 40) as u8).collect())
+                    .collect()
+            })
+            .collect::<Vec<Vec<Vec<u8>>>>();
+        let packed = pack_rasters(&rasters);
+        assert_eq!(packed.len(), 5 * 35 * 64);
+        for (i, raster) in rasters.iter().enumerate() {
+            assert_eq!(&unpack_raster(&packed, 35, 64, i), raster);
+        }
+    }
+}
@@ -0,0 +1,395 @@
+//! Writer for PCF fonts (Portable Compiled Format), the binary counterpart of BDF that X11
+//! servers and FreeType load directly.
+//!
+//! The file consists of a table of contents and the tables with the properties, the metrics,
+//! the bitmaps, and the encoding of the glyphs. Everything but the table of contents and the
+//! format of each table is stored with the most significant byte first. Wide glyphs occupy two
+//! cells, i.e., their metrics have twice the character width.
+
+use crate::bitmap_font::{glyph_name, BitmapFont, Property};
+use crate::monochrome::pack_row;
+
+/// Magic bytes at the beginning of a PCF font.
+const PCF_FILE_VERSION: [u8; 4] = *b"\x01fcp";
+
+const PCF_PROPERTIES: u32 = 1 << 0;
+const PCF_ACCELERATORS: u32 = 1 << 1;
+const PCF_METRICS: u32 = 1 << 2;
+const PCF_BITMAPS: u32 = 1 << 3;
+const PCF_BDF_ENCODINGS: u32 = 1 << 5;
+const PCF_SWIDTHS: u32 = 1 << 6;
+const PCF_GLYPH_NAMES: u32 = 1 << 7;
+const PCF_BDF_ACCELERATORS: u32 = 1 << 8;
+
+/// Integers with the most significant byte first.
+const PCF_BYTE_MASK: u32 = 1 << 2;
+/// Pixels with the most significant bit first.
+const PCF_BIT_MASK: u32 = 1 << 3;
+/// Rows of the bitmaps are padded to `1 << GLYPH_PAD_INDEX` bytes, like `bdftopcf` does.
+const GLYPH_PAD_INDEX: u32 = 2;
+/// Format of all tables: uncompressed metrics, accelerators without ink bounds.
+const FORMAT: u32 = PCF_BYTE_MASK | PCF_BIT_MASK | GLYPH_PAD_INDEX;
+
+/// Glyph index of chars without glyph in the encoding table.
+const NO_GLYPH: u16 = 0xffff;
+
+/// Encodes the font as PCF. Only chars of the Basic Multilingual Plane are supported.
+pub fn encode_pcf(font: &BitmapFont) -> Vec<u8> {
+    assert!(
+        font.glyphs.iter().all(|(c, _, _)| (*c as u32) <= 0xffff),
+        "PCF fonts only support chars of the Basic Multilingual Plane"
+    );
+    let tables = [
+        (PCF_PROPERTIES, properties_table(font)),
+        (PCF_ACCELERATORS, accelerators_table(font)),
+        (PCF_METRICS, metrics_table(font)),
+        (PCF_BITMAPS, bitmaps_table(font)),
+        (PCF_BDF_ENCODINGS, encodings_table(font)),
+        (PCF_SWIDTHS, swidths_table(font)),
+        (PCF_GLYPH_NAMES, glyph_names_table(font)),
+        (PCF_BDF_ACCELERATORS, accelerators_table(font)),
+    ];
+
+    let mut pcf = PCF_FILE_VERSION.to_vec();
+    pcf.extend_from_slice(&(tables.len() as u32).to_le_bytes());
+    let mut offset = pcf.len() + tables.len() * 16;
+    for (table_type, table) in &tables {
+        for field in [*table_type, FORMAT, table.len() as u32, offset as u32] {
+            pcf.extend_from_slice(&field.to_le_bytes());
+        }
+        offset += table.len();
+    }
+    for (_, table) in tables {
+        pcf.extend(table);
+    }
+    pcf
+}
+
+/// Builder for a table. Starts with the format and pads the table to a multiple of four bytes
+/// at the end.
+struct Table(Vec<u8>);
+
+impl Table {
+    fn new() -> Self {
+        Self(FORMAT.to_le_bytes().to_vec())
+    }
+
+    fn u8(&mut self, value: u8) -> &mut Self {
+        self.0.push(value);
+        self
+    }
+
+    fn i16(&mut self, value: i16) -> &mut Self {
+        self.0.extend_from_slice(&value.to_be_bytes());
+        self
+    }
+
+    fn i32(&mut self, value: i32) -> &mut Self {
+        self.0.extend_from_slice(&value.to_be_bytes());
+        self
+    }
+
+    fn bytes(&mut self, bytes: &[u8]) -> &mut Self {
+        self.0.extend_from_slice(bytes);
+        self
+    }
+
+    /// The metrics of a glyph that covers the given number of cells.
+    fn metrics(&mut self, font: &BitmapFont, cells: usize) -> &mut Self {
+        let width = font.glyph_width(cells) as i16;
+        self.i16(0) // left side bearing
+            .i16(width) // right side bearing
+            .i16(width) // character width
+            .i16(font.ascent as i16)
+            .i16(font.descent() as i16)
+            .i16(0) // attributes
+    }
+
+    fn finish(&mut self) -> Vec<u8> {
+        let mut table = std::mem::take(&mut self.0);
+        table.resize(table.len().next_multiple_of(4), 0);
+        table
+    }
+}
+
+/// Null-terminated strings and the offset of each string.
+fn string_table<'a>(strings: impl Iterator<Item = &'a str>) -> (Vec<u8>, Vec<i32>) {
+    let mut table = Vec::new();
+    let mut offsets = Vec::new();
+    for string in strings {
+        offsets.push(table.len() as i32);
+        table.extend_from_slice(string.as_bytes());
+        table.push(0);
+    }
+    (table, offsets)
+}
+
+fn properties_table(font: &BitmapFont) -> Vec<u8> {
+    let mut properties = vec![("FONT", Property::Str(font.xlfd_name()))];
+    properties.extend(font.properties());
+
+    let strings = properties.iter().flat_map(|(name, value)| match value {
+        Property::Int(_) => vec![*name],
+        Property::Str(value) => vec![*name, value.as_str()],
+    });
+    let (strings, offsets) = string_table(strings);
+    let mut offsets = offsets.into_iter();
+
+    let mut table = Table::new();
+    table.i32(properties.len() as i32);
+    for (_, value) in &properties {
+        table.i32(offsets.next().unwrap());
+        match value {
+            Property::Int(value) => table.u8(0).i32(*value),
+            Property::Str(_) => table.u8(1).i32(offsets.next().unwrap()),
+        };
+    }
+    let padding = (4 - properties.len() % 4) % 4;
+    table
+        .bytes(&vec![0; padding])
+        .i32(strings.len() as i32)
+        .bytes(&strings)
+        .finish()
+}
+
+fn accelerators_table(font: &BitmapFont) -> Vec<u8> {
+    let single_cells = u8::from(font.max_cells() == 1);
+    Table::new()
+        .u8(1) // no overlap
+        .u8(single_cells) // constant metrics
+        .u8(single_cells) // terminal font
+        .u8(single_cells) // constant width
+        .u8(1) // ink inside
+        .u8(0) // ink metrics
+        .u8(0) // draw direction: left to right
+        .u8(0) // padding
+        .i32(font.ascent as i32)
+        .i32(font.descent() as i32)
+        .i32(0) // max overlap
+        .metrics(font, 1) // min bounds
+        .metrics(font, font.max_cells()) // max bounds
+        .finish()
+}
+
+fn metrics_table(font: &BitmapFont) -> Vec<u8> {
+    let mut table = Table::new();
+    table.i32(font.glyphs.len() as i32);
+    for (_, cells, _) in &font.glyphs {
+        table.metrics(font, *cells);
+    }
+    table.finish()
+}
+
+fn bitmaps_table(font: &BitmapFont) -> Vec<u8> {
+    let bytes_per_row = |cells: usize| font.glyph_width(cells).div_ceil(8);
+    // size of the bitmap of a glyph with rows that are padded to `1 << pad_index` bytes
+    let glyph_size = |cells: usize, pad_index: u32| {
+        bytes_per_row(cells).next_multiple_of(1 << pad_index) * font.height
+    };
+
+    let mut table = Table::new();
+    table.i32(font.glyphs.len() as i32);
+    let mut offset = 0;
+    for (_, cells, _) in &font.glyphs {
+        table.i32(offset as i32);
+        offset += glyph_size(*cells, GLYPH_PAD_INDEX);
+    }
+    // size of all bitmaps for each glyph padding
+    for pad_index in 0..4 {
+        let size = font
+            .glyphs
+            .iter()
+            .map(|(_, cells, _)| glyph_size(*cells, pad_index))
+            .sum::<usize>();
+        table.i32(size as i32);
+    }
+    for (c, cells, bits) in &font.glyphs {
+        assert_eq!(
+            bits.len(),
+            font.height,
+            "glyph of '{}' has the wrong height",
+            c
+        );
+        for row in bits {
+            assert_eq!(
+                row.len(),
+                font.glyph_width(*cells),
+                "glyph of '{}' has the wrong width",
+                c
+            );
+            let mut row = pack_row(row);
+            row.resize(
+                bytes_per_row(*cells).next_multiple_of(1 << GLYPH_PAD_INDEX),
+                0,
+            );
+            table.bytes(&row);
+        }
+    }
+    table.finish()
+}
+
+fn encodings_table(font: &BitmapFont) -> Vec<u8> {
+    let codes = || font.glyphs.iter().map(|(c, _, _)| *c as u32);
+    let (min_byte1, max_byte1) = (codes().min().unwrap() >> 8, codes().max().unwrap() >> 8);
+    let min_byte2 = codes().map(|c| c & 0xff).min().unwrap();
+    let max_byte2 = codes().map(|c| c & 0xff).max().unwrap();
+    let columns = max_byte2 - min_byte2 + 1;
+
+    let rows = max_byte1 - min_byte1 + 1;
+    let mut indices = vec![NO_GLYPH; (rows * columns) as usize];
+    for (index, code) in codes().enumerate() {
+        let row = (code >> 8) - min_byte1;
+        let column = (code & 0xff) - min_byte2;
+        indices[(row * columns + column) as usize] = index as u16;
+    }
+    let default_char = font.default_char().map_or(NO_GLYPH, |c| c as u16);
+
+    let mut table = Table::new();
+    table
+        .i16(min_byte2 as i16)
+        .i16(max_byte2 as i16)
+        .i16(min_byte1 as i16)
+        .i16(max_byte1 as i16)
+        .i16(default_char as i16);
+    for index in indices {
+        table.i16(index as i16);
+    }
+    table.finish()
+}
+
+fn swidths_table(font: &BitmapFont) -> Vec<u8> {
+    let mut table = Table::new();
+    table.i32(font.glyphs.len() as i32);
+    for (_, cells, _) in &font.glyphs {
+        table.i32(font.scalable_width(*cells) as i32);
+    }
+    table.finish()
+}
+
+fn glyph_names_table(font: &BitmapFont) -> Vec<u8> {
+    let names = font
+        .glyphs
+        .iter()
+        .map(|(c, _, _)| glyph_name(*c))
+        .collect::<Vec<_>>();
+    let (strings, offsets) = string_table(names.iter().map(String::as_str));
+
+    let mut table = Table::new();
+    table.i32(names.len() as i32);
+    for offset in offsets {
+        table.i32(offset);
+    }
+    table.i32(strings.len() as i32).bytes(&strings).finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bitmap_font::tests::{test_font, test_font_with_wide_glyph};
+
+    /// Returns the tables of a PCF font by their type.
+    fn tables(pcf: &[u8]) -> Vec<(u32, &[u8])> {
+        let le = |i: usize| u32::from_le_bytes(pcf[i..i + 4].try_into().unwrap());
+        (0..le(4) as usize)
+            .map(|i| {
+                let entry = 8 + i * 16;
+                let (offset, size) = (le(entry + 12) as usize, le(entry + 8) as usize);
+                assert_eq!(le(entry + 4), FORMAT);
+                assert_eq!(offset % 4, 0);
+                (le(entry), &pcf[offset..offset + size])
+            })
+            .collect()
+    }
+
+    fn be16(table: &[u8], i: usize) -> i16 {
+        i16::from_be_bytes(table[i..i + 2].try_into().unwrap())
+    }
+
+    fn be32(table: &[u8], i: usize) -> i32 {
+        i32::from_be_bytes(table[i..i + 4].try_into().unwrap())
+    }
+
+    #[test]
+    fn test_encode_pcf() {
+        let pcf = encode_pcf(&test_font());
+        assert_eq!(pcf[..4], PCF_FILE_VERSION);
+        let tables = tables(&pcf);
+        let types = tables.iter().map(|(t, _)| *t).collect::<Vec<_>>();
+        assert_eq!(
+            types,
+            [
+                PCF_PROPERTIES,
+                PCF_ACCELERATORS,
+                PCF_METRICS,
+                PCF_BITMAPS,
+                PCF_BDF_ENCODINGS,
+                PCF_SWIDTHS,
+                PCF_GLYPH_NAMES,
+                PCF_BDF_ACCELERATORS
+            ]
+        );
+        // the tables end where the next one begins
+        assert_eq!(
+            pcf.len(),
+            8 + 16 * tables.len() + tables.iter().map(|(_, t)| t.len()).sum::<usize>()
+        );
+
+        // accelerators: ascent and descent
+        let accelerators = tables[1].1;
+        assert_eq!((be32(accelerators, 12), be32(accelerators, 16)), (2, 1));
+
+        // bitmaps: two glyphs with rows of two bytes that are padded to four bytes
+        let bitmaps = tables[3].1;
+        assert_eq!(be32(bitmaps, 4), 2);
+        assert_eq!((be32(bitmaps, 8), be32(bitmaps, 12)), (0, 12));
+        assert_eq!(be32(bitmaps, 16 + 2 * 4), 24);
+        let glyph = &bitmaps[32 + 12..32 + 24];
+        assert_eq!(
+            glyph,
+            [0x80, 0x40, 0, 0, 0xff, 0xc0, 0, 0, 0x80, 0x40, 0, 0]
+        );
+
+        // encodings: 'A' (0x0041) and '�' (0xfffd)
+        let encodings = tables[4].1;
+        let header = (1..=5).map(|i| be16(encodings, i * 2 + 2) as u16);
+        assert_eq!(header.collect::<Vec<_>>(), [0x41, 0xfd, 0x00, 0xff, 0xfffd]);
+        let columns = 0xfd - 0x41 + 1;
+        let index = |code: usize| {
+            let (row, column) = ((code >> 8), (code & 0xff) - 0x41);
+            be16(encodings, 14 + (row * columns + column) * 2) as u16
+        };
+        assert_eq!((index(0x41), index(0xfffd), index(0x42)), (0, 1, NO_GLYPH));
+    }
+
+    #[test]
+    fn test_encode_pcf_wide_glyph() {
+        let pcf = encode_pcf(&test_font_with_wide_glyph());
+        let tables = tables(&pcf);
+
+        // accelerators: the metrics are not constant, the max bounds are two cells wide
+        let accelerators = tables[1].1;
+        assert_eq!(accelerators[4..8], [1, 0, 0, 0]);
+        assert_eq!(
+            (be16(accelerators, 24 + 4), be16(accelerators, 36 + 4)),
+            (10, 20)
+        );
+
+        // metrics: the character width of each glyph
+        let metrics = tables[2].1;
+        let widths = (0..3).map(|i| be16(metrics, 8 + i * 12 + 4));
+        assert_eq!(widths.collect::<Vec<_>>(), [10, 10, 20]);
+
+        // bitmaps: rows of three bytes that are padded to four bytes
+        let bitmaps = tables[3].1;
+        assert_eq!(be32(bitmaps, 4), 3);
+        assert_eq!(be32(bitmaps, 8 + 2 * 4), 24);
+        // sizes of all bitmaps with rows padded to 1 and to 4 bytes
+        assert_eq!((be32(bitmaps, 20), be32(bitmaps, 20 + 2 * 4)), (21, 36));
+        let glyph = &bitmaps[36 + 24..36 + 36];
+        assert_eq!(glyph, [0x80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x10, 0]);
+
+        // scalable widths
+        let swidths = tables[5].1;
+        assert_eq!(be32(swidths, 8 + 2 * 4), 6666);
+    }
+}
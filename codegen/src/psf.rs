@@ -0,0 +1,102 @@
+//! Writer for PSF2 fonts, the format of the Linux text console (`setfont`).
+//!
+//! The font has one glyph per char and a unicode table in UTF-8 that maps each glyph to its
+//! char. The counterpart is the parser in `codegen_templates/psf.rs.txt`.
+//!
+//! All glyphs of a PSF font have the size of a single cell, and the Linux console has no wide
+//! chars. Hence, wide glyphs, which occupy two cells, are left out.
+
+use crate::bitmap_font::BitmapFont;
+use crate::monochrome::pack_row;
+
+/// Magic bytes at the beginning of a PSF2 font.
+const PSF2_MAGIC: [u8; 4] = [0x72, 0xb5, 0x4a, 0x86];
+/// Size of the PSF2 header in bytes.
+const PSF2_HEADER_SIZE: u32 = 32;
+/// PSF2 header flag: the font has a unicode table.
+const PSF2_HAS_UNICODE_TABLE: u32 = 0x01;
+/// PSF2 unicode table: ends the entries of a glyph.
+const PSF2_SEPARATOR: u8 = 0xff;
+
+/// Encodes a PSF2 font with a unicode table. Wide glyphs are left out.
+pub fn encode_psf2(font: &BitmapFont) -> Vec<u8> {
+    let bytes_per_glyph = font.width.div_ceil(8) * font.height;
+    let glyphs = font
+        .glyphs
+        .iter()
+        .filter(|(_, cells, _)| *cells == 1)
+        .collect::<Vec<_>>();
+
+    let mut psf = PSF2_MAGIC.to_vec();
+    for field in [
+        0, // version
+        PSF2_HEADER_SIZE,
+        PSF2_HAS_UNICODE_TABLE,
+        glyphs.len() as u32,
+        bytes_per_glyph as u32,
+        font.height as u32,
+        font.width as u32,
+    ] {
+        psf.extend_from_slice(&field.to_le_bytes());
+    }
+
+    for (c, _, bits) in &glyphs {
+        assert_eq!(
+            bits.len(),
+            font.height,
+            "glyph of '{}' has the wrong height",
+            c
+        );
+        for row in bits {
+            assert_eq!(
+                row.len(),
+                font.width,
+                "glyph of '{}' has the wrong width",
+                c
+            );
+            psf.extend(pack_row(row));
+        }
+    }
+
+    for (c, _, _) in &glyphs {
+        psf.extend_from_slice(c.to_string().as_bytes());
+        psf.push(PSF2_SEPARATOR);
+    }
+    psf
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bitmap_font::tests::{test_font, test_font_with_wide_glyph};
+
+    #[test]
+    fn test_encode_psf2() {
+        let psf = encode_psf2(&test_font());
+
+        let field = |i: usize| u32::from_le_bytes(psf[i * 4..i * 4 + 4].try_into().unwrap());
+        assert_eq!(psf[..4], PSF2_MAGIC);
+        assert_eq!(field(2), PSF2_HEADER_SIZE);
+        assert_eq!(field(3), PSF2_HAS_UNICODE_TABLE);
+        // glyph count, bytes per glyph, height, width
+        assert_eq!([field(4), field(5), field(6), field(7)], [2, 6, 3, 10]);
+
+        let glyph_data = &psf[32..44];
+        assert_eq!(
+            glyph_data,
+            [0, 0, 0, 0, 0, 0, 0x80, 0x40, 0xff, 0xc0, 0x80, 0x40]
+        );
+        let mut unicode_table = b"A\xff".to_vec();
+        unicode_table.extend_from_slice("�".as_bytes());
+        unicode_table.push(0xff);
+        assert_eq!(psf[44..], unicode_table);
+    }
+
+    #[test]
+    fn test_encode_psf2_skips_wide_glyphs() {
+        assert_eq!(
+            encode_psf2(&test_font_with_wide_glyph()),
+            encode_psf2(&test_font())
+        );
+    }
+}
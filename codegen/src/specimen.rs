@@ -0,0 +1,143 @@
+//! Specimen sheets that show all glyphs of a font weight and raster height in one image, so
+//! that they can be reviewed without a window. See the `specimen` binary.
+//!
+//! The glyphs are laid out in a grid of [`COLUMNS`] columns in the order of the unicode ranges.
+//! Each slot of the grid has the code point as label above the raster. The raster is tinted, so
+//! that its bounds are visible, and the first row below the baseline is drawn as a red guide.
+
+use crate::font::RasterizationInfo;
+use crate::image::{Image, Rgb};
+
+/// Number of glyphs in each row of the grid.
+pub const COLUMNS: usize = 16;
+
+/// Space around the labels and rasters in pixels.
+const PADDING: usize = 4;
+
+const BACKGROUND: Rgb = [255, 255, 255];
+const GRID: Rgb = [190, 190, 190];
+const RASTER_BACKGROUND: Rgb = [232, 238, 250];
+const BASELINE: Rgb = [255, 170, 170];
+const LABEL: Rgb = [90, 90, 90];
+const INK: Rgb = [0, 0, 0];
+
+/// The glyphs of a font weight and raster height.
+#[derive(Debug)]
+pub struct Specimen {
+    /// Title at the top of the sheet, such as "Noto Sans Mono regular 16px".
+    pub title: String,
+    /// Width of a cell in pixels.
+    pub width: usize,
+    /// Height of each raster in pixels.
+    pub height: usize,
+    /// Rows above the baseline.
+    pub baseline: usize,
+    /// Chars with their raster, which is one or two cells wide.
+    pub glyphs: Vec<(char, Vec<Vec<u8>>)>,
+}
+
+/// Renders the specimen sheet. The title and the labels are drawn with `label_font`.
+pub fn render_specimen(specimen: &Specimen, label_font: &RasterizationInfo) -> Image {
+    let labels = specimen
+        .glyphs
+        .iter()
+        .map(|(c, _)| format!("U+{:04X}", *c as u32))
+        .collect::<Vec<_>>();
+    let label_width = labels.iter().map(String::len).max().unwrap_or(0) * label_font.raster_width();
+    let slot_width = label_width.max(2 * specimen.width) + 2 * PADDING;
+    let slot_height = label_font.raster_height() + specimen.height + 3 * PADDING;
+    let title_height = label_font.raster_height() + 2 * PADDING;
+    let rows = specimen.glyphs.len().div_ceil(COLUMNS);
+
+    // one more pixel for the last line of the grid
+    let mut image = Image::new(
+        COLUMNS * slot_width + 1,
+        title_height + rows * slot_height + 1,
+        BACKGROUND,
+    );
+    draw_text(
+        &mut image,
+        PADDING,
+        PADDING,
+        &specimen.title,
+        label_font,
+        INK,
+    );
+
+    for row in 0..=rows {
+        let y = title_height + row * slot_height;
+        image.fill_rect(0, y, image.width(), 1, GRID);
+    }
+    for column in 0..=COLUMNS {
+        image.fill_rect(
+            column * slot_width,
+            title_height,
+            1,
+            rows * slot_height,
+            GRID,
+        );
+    }
+
+    for (i, ((_, raster), label)) in specimen.glyphs.iter().zip(&labels).enumerate() {
+        let x = (i % COLUMNS) * slot_width + PADDING;
+        let y = title_height + (i / COLUMNS) * slot_height + PADDING;
+        draw_text(&mut image, x, y, label, label_font, LABEL);
+
+        let y = y + label_font.raster_height() + PADDING;
+        let raster_width = raster.first().map_or(0, Vec::len);
+        image.fill_rect(x, y, raster_width, specimen.height, RASTER_BACKGROUND);
+        image.fill_rect(x, y + specimen.baseline, raster_width, 1, BASELINE);
+        image.draw_raster(x, y, raster, INK);
+    }
+    image
+}
+
+/// Draws a line of text with its top left corner at (x, y).
+fn draw_text(
+    image: &mut Image,
+    x: usize,
+    y: usize,
+    text: &str,
+    font: &RasterizationInfo,
+    color: Rgb,
+) {
+    for (i, c) in text.chars().enumerate() {
+        image.draw_raster(x + i * font.raster_width(), y, &font.rasterize(c), color);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::test_config;
+
+    #[test]
+    fn test_render_specimen() {
+        let label_font = RasterizationInfo::new(10, test_config().font_weights()[1].font());
+        let specimen = Specimen {
+            title: "Test".to_string(),
+            width: 4,
+            height: 6,
+            baseline: 4,
+            glyphs: (0..17).map(|_| ('A', vec![vec![255; 4]; 6])).collect(),
+        };
+        let image = render_specimen(&specimen, &label_font);
+
+        let slot_width = 6 * label_font.raster_width() + 2 * PADDING;
+        let slot_height = label_font.raster_height() + 6 + 3 * PADDING;
+        let title_height = label_font.raster_height() + 2 * PADDING;
+        // 17 glyphs need two rows
+        assert_eq!(image.width(), COLUMNS * slot_width + 1);
+        assert_eq!(image.height(), title_height + 2 * slot_height + 1);
+        assert_eq!(image.pixel(0, title_height), GRID);
+        assert_eq!(image.pixel(slot_width, title_height + 1), GRID);
+
+        // the raster of the first glyph covers the baseline guide
+        let raster_y = title_height + PADDING + label_font.raster_height() + PADDING;
+        assert_eq!(image.pixel(PADDING, raster_y + 4), INK);
+        // the second row has only one glyph, the rest of its slots are empty
+        let y = raster_y + slot_height;
+        assert_eq!(image.pixel(PADDING, y), INK);
+        assert_eq!(image.pixel(slot_width + PADDING, y), BACKGROUND);
+    }
+}
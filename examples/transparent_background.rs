@@ -1,5 +1,6 @@
 use minifb::{Key, Window, WindowOptions};
-use noto_sans_mono_bitmap::{get_raster, FontWeight, RasterHeight};
+use noto_sans_mono_bitmap::glyph_source::{GlyphSource, NotoSansMono};
+use noto_sans_mono_bitmap::{FontWeight, RasterHeight};
 
 const WIDTH: usize = 800;
 const HEIGH: usize = 600;
@@ -21,14 +22,8 @@ fn main() {
         draw_buffer[0..NUM_PIXELS].fill(fill_colour);
 
         let msg = "Hello World";
-        draw_string(
-            msg,
-            400,
-            300,
-            FontWeight::Regular,
-            RasterHeight::Size16,
-            &mut draw_buffer,
-        );
+        let font = NotoSansMono::new(FontWeight::Regular, RasterHeight::Size16);
+        draw_string(msg, 400, 300, &font, &mut draw_buffer);
 
         // We unwrap here as we want this code to exit if it fails. Real applications may want to handle this in a different way
         window
@@ -37,24 +32,16 @@ fn main() {
     }
 }
 
-fn draw_string(
-    msg: &str,
-    x: u32,
-    y: u32,
-    font_weight: FontWeight,
-    raster_height: RasterHeight,
-    draw_buffer: &mut Vec<u32>,
-) {
-    for (char_i, char) in msg.chars().enumerate() {
-        let char_raster = get_raster(char, font_weight, raster_height).expect("unknown char");
-        for (row_i, row) in char_raster.raster().iter().enumerate() {
-            for (col_i, intensity) in row.iter().enumerate() {
+fn draw_string(msg: &str, x: u32, y: u32, font: &impl GlyphSource, draw_buffer: &mut Vec<u32>) {
+    //Any glyph source works here, e.g. the font chained with own glyphs or a PSF font
+    let mut char_x = 0;
+    for char in msg.chars() {
+        let glyph = font.glyph(char).expect("unknown char");
+        for row_i in 0..glyph.height() {
+            for col_i in 0..glyph.width() {
+                let intensity = glyph.pixel(col_i, row_i);
                 //Figure out the index of the pixel to update
-                let index = char_i * char_raster.width()
-                    + col_i
-                    + row_i * WIDTH
-                    + (x as usize)
-                    + (y as usize * WIDTH);
+                let index = char_x + col_i + row_i * WIDTH + (x as usize) + (y as usize * WIDTH);
 
                 //Sample the pixel, as we need to add the intensity to it
                 let curr_pixel_rgb = draw_buffer[index];
@@ -65,14 +52,15 @@ fn draw_string(
                 let mut b = (curr_pixel_rgb & 0xff) as u8;
 
                 //Use a saturating add to clamp to max u8 value
-                r = r.saturating_add(*intensity);
-                g = g.saturating_add(*intensity);
-                b = b.saturating_add(*intensity);
+                r = r.saturating_add(intensity);
+                g = g.saturating_add(intensity);
+                b = b.saturating_add(intensity);
 
                 let new_pixel_rgb = ((r as u32) << 16) + ((g as u32) << 8) + (b as u32);
 
                 draw_buffer[index] = new_pixel_rgb;
             }
         }
+        char_x += glyph.width();
     }
 }
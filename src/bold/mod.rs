@@ -1,5 +1,11 @@
 //! Module for letters with the font weight bold.
 
+#[cfg(feature = "size_10")]
+pub mod size_10;
+#[cfg(feature = "size_12")]
+pub mod size_12;
+#[cfg(feature = "size_14")]
+pub mod size_14;
 #[cfg(feature = "size_16")]
 pub mod size_16;
 #[cfg(feature = "size_20")]
@@ -8,3 +14,11 @@ pub mod size_20;
 pub mod size_24;
 #[cfg(feature = "size_32")]
 pub mod size_32;
+#[cfg(feature = "size_40")]
+pub mod size_40;
+#[cfg(feature = "size_48")]
+pub mod size_48;
+#[cfg(feature = "size_64")]
+pub mod size_64;
+#[cfg(feature = "size_8")]
+pub mod size_8;
@@ -0,0 +1,522 @@
+//! Module for letters with the font weight bold and size 10.
+//!
+//! # Font License
+//! * Downloaded from: <https://fonts.google.com/noto/specimen/Noto+Sans+Mono>
+//! * License: SIL Open Font License (OFL) <https://scripts.sil.org/cms/scripts/page.php?site_id=nrsi&id=OFL>
+
+use crate::raster_table::RasterTable;
+
+/// The constant height of each rasterized character for the given font weight
+/// and size. This size corresponds to the size of the module name. The font
+/// size will be a few percent smaller, as each raster contains a small vertical
+/// to ensure vertical alignment of multiple characters.
+#[allow(dead_code)]
+pub const RASTER_HEIGHT: usize = 10;
+
+/// The constant width of each rasterized character for the given font weight and
+/// size. This is less than [`RASTER_HEIGHT`], so that multiple letters can be print
+/// next to each other and look "nice" out-of-the-box, hence, library users do not
+/// have to perform additional alignment of characters or fill in padding.
+pub const RASTER_WIDTH: usize = 5;
+
+/// The rasters of the unicode range `unicode-basic-latin`.
+#[cfg(feature = "unicode-basic-latin")]
+static UNICODE_BASIC_LATIN: RasterTable = RasterTable::new(
+    &[
+        ' ', '!', '"', '#', '$', '%', '&', '\'', '(', ')', '*', '+', ',', '-', '.', '/', '0', '1',
+        '2', '3', '4', '5', '6', '7', '8', '9', ':', ';', '<', '=', '>', '?', '@', 'A', 'B', 'C',
+        'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R', 'S', 'T', 'U',
+        'V', 'W', 'X', 'Y', 'Z', '[', '\\', ']', '^', '_', '`', 'a', 'b', 'c', 'd', 'e', 'f', 'g',
+        'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y',
+        'z', '{', '|', '}', '~',
+    ],
+    include_bytes!("../res_rasterized_characters/unicode-basic-latin_h10_wBold.bin"),
+);
+
+/// The rasters of the unicode range `unicode-latin-1-supplement`.
+#[cfg(feature = "unicode-latin-1-supplement")]
+static UNICODE_LATIN_1_SUPPLEMENT: RasterTable = RasterTable::new(
+    &[
+        '¡', '¢', '£', '¤', '¥', '¦', '§', '¨', '©', 'ª', '«', '¬', '®', '¯', '°', '±', '²', '³',
+        '´', 'µ', '¶', '·', '¸', '¹', 'º', '»', '¼', '½', '¾', '¿', 'À', 'Á', 'Â', 'Ã', 'Ä', 'Å',
+        'Æ', 'Ç', 'È', 'É', 'Ê', 'Ë', 'Ì', 'Í', 'Î', 'Ï', 'Ð', 'Ñ', 'Ò', 'Ó', 'Ô', 'Õ', 'Ö', '×',
+        'Ø', 'Ù', 'Ú', 'Û', 'Ü', 'Ý', 'Þ', 'ß', 'à', 'á', 'â', 'ã', 'ä', 'å', 'æ', 'ç', 'è', 'é',
+        'ê', 'ë', 'ì', 'í', 'î', 'ï', 'ð', 'ñ', 'ò', 'ó', 'ô', 'õ', 'ö', '÷', 'ø', 'ù', 'ú', 'û',
+        'ü', 'ý', 'þ', 'ÿ',
+    ],
+    include_bytes!("../res_rasterized_characters/unicode-latin-1-supplement_h10_wBold.bin"),
+);
+
+/// The rasters of the unicode range `unicode-latin-extended-a`.
+#[cfg(feature = "unicode-latin-extended-a")]
+static UNICODE_LATIN_EXTENDED_A: RasterTable = RasterTable::new(
+    &[
+        'Ā', 'ā', 'Ă', 'ă', 'Ą', 'ą', 'Ć', 'ć', 'Ĉ', 'ĉ', 'Ċ', 'ċ', 'Č', 'č', 'Ď', 'ď', 'Đ', 'đ',
+        'Ē', 'ē', 'Ĕ', 'ĕ', 'Ė', 'ė', 'Ę', 'ę', 'Ě', 'ě', 'Ĝ', 'ĝ', 'Ğ', 'ğ', 'Ġ', 'ġ', 'Ģ', 'ģ',
+        'Ĥ', 'ĥ', 'Ħ', 'ħ', 'Ĩ', 'ĩ', 'Ī', 'ī', 'Ĭ', 'ĭ', 'Į', 'į', 'İ', 'ı', 'Ĳ', 'ĳ', 'Ĵ', 'ĵ',
+        'Ķ', 'ķ', 'ĸ', 'Ĺ', 'ĺ', 'Ļ', 'ļ', 'Ľ', 'ľ', 'Ŀ', 'ŀ', 'Ł', 'ł', 'Ń', 'ń', 'Ņ', 'ņ', 'Ň',
+        'ň', 'ŉ', 'Ŋ', 'ŋ', 'Ō', 'ō', 'Ŏ', 'ŏ', 'Ő', 'ő', 'Œ', 'œ', 'Ŕ', 'ŕ', 'Ŗ', 'ŗ', 'Ř', 'ř',
+        'Ś', 'ś', 'Ŝ', 'ŝ', 'Ş', 'ş', 'Š', 'š', 'Ţ', 'ţ', 'Ť', 'ť', 'Ŧ', 'ŧ', 'Ũ', 'ũ', 'Ū', 'ū',
+        'Ŭ', 'ŭ', 'Ů', 'ů', 'Ű', 'ű', 'Ų', 'ų', 'Ŵ', 'ŵ', 'Ŷ', 'ŷ', 'Ÿ', 'Ź', 'ź', 'Ż', 'ż', 'Ž',
+        'ž', 'ſ',
+    ],
+    include_bytes!("../res_rasterized_characters/unicode-latin-extended-a_h10_wBold.bin"),
+);
+
+/// The rasters of the unicode range `unicode-latin-extended-b`.
+#[cfg(feature = "unicode-latin-extended-b")]
+static UNICODE_LATIN_EXTENDED_B: RasterTable = RasterTable::new(
+    &[
+        'ƀ', 'Ɓ', 'Ƃ', 'ƃ', 'Ƅ', 'ƅ', 'Ɔ', 'Ƈ', 'ƈ', 'Ɖ', 'Ɗ', 'Ƌ', 'ƌ', 'ƍ', 'Ǝ', 'Ə', 'Ɛ', 'Ƒ',
+        'ƒ', 'Ɠ', 'Ɣ', 'ƕ', 'Ɩ', 'Ɨ', 'Ƙ', 'ƙ', 'ƚ', 'ƛ', 'Ɯ', 'Ɲ', 'ƞ', 'Ɵ', 'Ơ', 'ơ', 'ƣ', 'Ƥ',
+        'ƥ', 'Ʀ', 'Ƨ', 'ƨ', 'Ʃ', 'ƪ', 'ƫ', 'Ƭ', 'ƭ', 'Ʈ', 'Ư', 'ư', 'Ʊ', 'Ʋ', 'Ƴ', 'ƴ', 'Ƶ', 'ƶ',
+        'Ʒ', 'Ƹ', 'ƹ', 'ƺ', 'ƻ', 'Ƽ', 'ƽ', 'ƾ', 'ƿ', 'ǀ', 'ǁ', 'ǂ', 'ǃ', 'Ǎ', 'ǎ', 'Ǐ', 'ǐ', 'Ǒ',
+        'ǒ', 'Ǔ', 'ǔ', 'Ǖ', 'ǖ', 'Ǘ', 'ǘ', 'Ǚ', 'ǚ', 'Ǜ', 'ǜ', 'ǝ', 'Ǟ', 'ǟ', 'Ǡ', 'ǡ', 'Ǣ', 'ǣ',
+        'Ǥ', 'ǥ', 'Ǧ', 'ǧ', 'Ǩ', 'ǩ', 'Ǫ', 'ǫ', 'Ǭ', 'ǭ', 'Ǯ', 'ǯ', 'ǰ', 'Ǵ', 'ǵ', 'Ƕ', 'Ƿ', 'Ǹ',
+        'ǹ', 'Ǻ', 'ǻ', 'Ǽ', 'ǽ', 'Ǿ', 'ǿ', 'Ȁ', 'ȁ', 'Ȃ', 'ȃ', 'Ȅ', 'ȅ', 'Ȇ', 'ȇ', 'Ȉ', 'ȉ', 'Ȋ',
+        'ȋ', 'Ȍ', 'ȍ', 'Ȏ', 'ȏ', 'Ȑ', 'ȑ', 'Ȓ', 'ȓ', 'Ȕ', 'ȕ', 'Ȗ', 'ȗ', 'Ș', 'ș', 'Ț', 'ț', 'Ȝ',
+        'ȝ', 'Ȟ', 'ȟ', 'Ƞ', 'ȡ', 'Ȣ', 'ȣ', 'Ȥ', 'ȥ', 'Ȧ', 'ȧ', 'Ȩ', 'ȩ', 'Ȫ', 'ȫ', 'Ȭ', 'ȭ', 'Ȯ',
+        'ȯ', 'Ȱ', 'ȱ', 'Ȳ', 'ȳ', 'ȴ', 'ȵ', 'ȶ', 'ȷ', 'ȸ', 'ȹ', 'Ⱥ', 'Ȼ', 'ȼ', 'Ƚ', 'Ⱦ', 'ȿ', 'ɀ',
+        'Ɂ', 'ɂ', 'Ƀ', 'Ʉ', 'Ʌ', 'Ɇ', 'ɇ', 'Ɉ', 'ɉ', 'Ɋ', 'ɋ', 'Ɍ', 'ɍ', 'Ɏ', 'ɏ',
+    ],
+    include_bytes!("../res_rasterized_characters/unicode-latin-extended-b_h10_wBold.bin"),
+);
+
+/// The rasters of the wide chars of the unicode range `unicode-latin-extended-b`, which span two cells.
+#[cfg(feature = "unicode-latin-extended-b")]
+static UNICODE_LATIN_EXTENDED_B_WIDE: RasterTable = RasterTable::new(
+    &[
+        'Ƣ', 'Ǆ', 'ǅ', 'ǆ', 'Ǉ', 'ǈ', 'ǉ', 'Ǌ', 'ǋ', 'ǌ', 'Ǳ', 'ǲ', 'ǳ',
+    ],
+    include_bytes!("../res_rasterized_characters/unicode-latin-extended-b-wide_h10_wBold.bin"),
+);
+
+/// The rasters of the unicode range `unicode-ipa-extensions`.
+#[cfg(feature = "unicode-ipa-extensions")]
+static UNICODE_IPA_EXTENSIONS: RasterTable = RasterTable::new(
+    &[
+        'ɐ', 'ɑ', 'ɒ', 'ɓ', 'ɔ', 'ɕ', 'ɖ', 'ɗ', 'ɘ', 'ə', 'ɚ', 'ɛ', 'ɜ', 'ɝ', 'ɞ', 'ɟ', 'ɠ', 'ɡ',
+        'ɢ', 'ɣ', 'ɤ', 'ɥ', 'ɦ', 'ɧ', 'ɨ', 'ɩ', 'ɪ', 'ɫ', 'ɬ', 'ɭ', 'ɮ', 'ɯ', 'ɰ', 'ɱ', 'ɲ', 'ɳ',
+        'ɴ', 'ɵ', 'ɶ', 'ɷ', 'ɸ', 'ɹ', 'ɺ', 'ɻ', 'ɼ', 'ɽ', 'ɾ', 'ɿ', 'ʀ', 'ʁ', 'ʂ', 'ʃ', 'ʄ', 'ʅ',
+        'ʆ', 'ʇ', 'ʈ', 'ʉ', 'ʊ', 'ʋ', 'ʌ', 'ʍ', 'ʎ', 'ʏ', 'ʐ', 'ʑ', 'ʒ', 'ʓ', 'ʔ', 'ʕ', 'ʖ', 'ʗ',
+        'ʘ', 'ʙ', 'ʚ', 'ʛ', 'ʜ', 'ʝ', 'ʞ', 'ʟ', 'ʠ', 'ʡ', 'ʢ', 'ʣ', 'ʤ', 'ʦ', 'ʧ', 'ʩ', 'ʪ', 'ʫ',
+        'ʬ', 'ʭ', 'ʮ', 'ʯ',
+    ],
+    include_bytes!("../res_rasterized_characters/unicode-ipa-extensions_h10_wBold.bin"),
+);
+
+/// The rasters of the wide chars of the unicode range `unicode-ipa-extensions`, which span two cells.
+#[cfg(feature = "unicode-ipa-extensions")]
+static UNICODE_IPA_EXTENSIONS_WIDE: RasterTable = RasterTable::new(
+    &['ʥ', 'ʨ'],
+    include_bytes!("../res_rasterized_characters/unicode-ipa-extensions-wide_h10_wBold.bin"),
+);
+
+/// The rasters of the unicode range `unicode-combining-diacritical-marks`.
+#[cfg(feature = "unicode-combining-diacritical-marks")]
+static UNICODE_COMBINING_DIACRITICAL_MARKS: RasterTable = RasterTable::new(
+    &[
+        '̀', '́', '̂', '̃', '̄', '̅', '̆', '̇', '̈', '̉', '̊', '̋', '̌', '̍', '̎', '̏', '̐', '̑', '̒', '̓', '̔', '̕', '̖',
+        '̗', '̘', '̙', '̚', '̛', '̜', '̝', '̞', '̟', '̠', '̡', '̢', '̣', '̤', '̥', '̦', '̧', '̨', '̩', '̪', '̫', '̬', '̭',
+        '̮', '̯', '̰', '̱', '̲', '̳', '̴', '̵', '̶', '̷', '̸', '̹', '̺', '̻', '̼', '̽', '̾', '̿', '̀', '́', '͂', '̓', '̈́',
+        'ͅ', '͆', '͇', '͈', '͉', '͊', '͋', '͌', '͍', '͎', '͐', '͑', '͒', '͓', '͔', '͕', '͖', '͗', '͘', '͙', '͚', '͛', '͜',
+        '͝', '͞', '͟', '͠', '͡', '͢', 'ͣ', 'ͤ', 'ͥ', 'ͦ', 'ͧ', 'ͨ', 'ͩ', 'ͪ', 'ͫ', 'ͬ', 'ͭ', 'ͮ', 'ͯ',
+    ],
+    include_bytes!(
+        "../res_rasterized_characters/unicode-combining-diacritical-marks_h10_wBold.bin"
+    ),
+);
+
+/// The rasters of the unicode range `unicode-greek`.
+#[cfg(feature = "unicode-greek")]
+static UNICODE_GREEK: RasterTable = RasterTable::new(
+    &[
+        'Ͱ', 'ͱ', 'Ͳ', 'ͳ', 'ʹ', '͵', 'Ͷ', 'ͷ', 'ͺ', 'ͻ', 'ͼ', 'ͽ', ';', 'Ϳ', '΄', '΅', 'Ά', '·',
+        'Έ', 'Ή', 'Ί', 'Ό', 'Ύ', 'Ώ', 'ΐ', 'Α', 'Β', 'Γ', 'Δ', 'Ε', 'Ζ', 'Η', 'Θ', 'Ι', 'Κ', 'Λ',
+        'Μ', 'Ν', 'Ξ', 'Ο', 'Π', 'Ρ', 'Σ', 'Τ', 'Υ', 'Φ', 'Χ', 'Ψ', 'Ω', 'Ϊ', 'Ϋ', 'ά', 'έ', 'ή',
+        'ί', 'ΰ', 'α', 'β', 'γ', 'δ', 'ε', 'ζ', 'η', 'θ', 'ι', 'κ', 'λ', 'μ', 'ν', 'ξ', 'ο', 'π',
+        'ρ', 'ς', 'σ', 'τ', 'υ', 'φ', 'χ', 'ψ', 'ω', 'ϊ', 'ϋ', 'ό', 'ύ', 'ώ', 'Ϗ', 'ϐ', 'ϑ', 'ϒ',
+        'ϓ', 'ϔ', 'ϕ', 'ϖ', 'ϗ', 'Ϙ', 'ϙ', 'Ϛ', 'ϛ', 'Ϝ', 'ϝ', 'Ϟ', 'ϟ', 'Ϡ', 'ϡ', 'ϰ', 'ϱ', 'ϲ',
+        'ϳ', 'ϴ', 'ϵ', '϶', 'Ϸ', 'ϸ', 'Ϲ', 'Ϻ', 'ϻ', 'ϼ', 'Ͻ', 'Ͼ', 'Ͽ',
+    ],
+    include_bytes!("../res_rasterized_characters/unicode-greek_h10_wBold.bin"),
+);
+
+/// The rasters of the unicode range `unicode-cyrillic`.
+#[cfg(feature = "unicode-cyrillic")]
+static UNICODE_CYRILLIC: RasterTable = RasterTable::new(
+    &[
+        'Ѐ', 'Ё', 'Ђ', 'Ѓ', 'Є', 'Ѕ', 'І', 'Ї', 'Ј', 'Љ', 'Њ', 'Ћ', 'Ќ', 'Ѝ', 'Ў', 'Џ', 'А', 'Б',
+        'В', 'Г', 'Д', 'Е', 'Ж', 'З', 'И', 'Й', 'К', 'Л', 'М', 'Н', 'О', 'П', 'Р', 'С', 'Т', 'У',
+        'Ф', 'Х', 'Ц', 'Ч', 'Ш', 'Щ', 'Ъ', 'Ы', 'Ь', 'Э', 'Ю', 'Я', 'а', 'б', 'в', 'г', 'д', 'е',
+        'ж', 'з', 'и', 'й', 'к', 'л', 'м', 'н', 'о', 'п', 'р', 'с', 'т', 'у', 'ф', 'х', 'ц', 'ч',
+        'ш', 'щ', 'ъ', 'ы', 'ь', 'э', 'ю', 'я', 'ѐ', 'ё', 'ђ', 'ѓ', 'є', 'ѕ', 'і', 'ї', 'ј', 'љ',
+        'њ', 'ћ', 'ќ', 'ѝ', 'ў', 'џ', 'Ѡ', 'ѡ', 'Ѣ', 'ѣ', 'Ѥ', 'ѥ', 'Ѧ', 'ѧ', 'ѩ', 'Ѫ', 'ѫ', 'Ѯ',
+        'ѯ', 'Ѱ', 'ѱ', 'Ѳ', 'ѳ', 'Ѵ', 'ѵ', 'Ѷ', 'ѷ', 'Ѻ', 'ѻ', 'Ѽ', 'ѽ', 'Ѿ', 'ѿ', 'Ҁ', 'ҁ', '҂',
+        'Ҋ', 'ҋ', 'Ҍ', 'ҍ', 'Ҏ', 'ҏ', 'Ґ', 'ґ', 'Ғ', 'ғ', 'Ҕ', 'ҕ', 'Җ', 'җ', 'Ҙ', 'ҙ', 'Қ', 'қ',
+        'Ҝ', 'ҝ', 'Ҟ', 'ҟ', 'Ҡ', 'ҡ', 'Ң', 'ң', 'Ҥ', 'ҥ', 'Ҧ', 'ҧ', 'Ҩ', 'ҩ', 'Ҫ', 'ҫ', 'Ҭ', 'ҭ',
+        'Ү', 'ү', 'Ұ', 'ұ', 'Ҳ', 'ҳ', 'Ҵ', 'ҵ', 'Ҷ', 'ҷ', 'Ҹ', 'ҹ', 'Һ', 'һ', 'Ҽ', 'ҽ', 'Ҿ', 'ҿ',
+        'Ӏ', 'Ӂ', 'ӂ', 'Ӄ', 'ӄ', 'Ӆ', 'ӆ', 'Ӈ', 'ӈ', 'Ӊ', 'ӊ', 'Ӌ', 'ӌ', 'Ӎ', 'ӎ', 'ӏ', 'Ӑ', 'ӑ',
+        'Ӓ', 'ӓ', 'Ӕ', 'ӕ', 'Ӗ', 'ӗ', 'Ә', 'ә', 'Ӛ', 'ӛ', 'Ӝ', 'ӝ', 'Ӟ', 'ӟ', 'Ӡ', 'ӡ', 'Ӣ', 'ӣ',
+        'Ӥ', 'ӥ', 'Ӧ', 'ӧ', 'Ө', 'ө', 'Ӫ', 'ӫ', 'Ӭ', 'ӭ', 'Ӯ', 'ӯ', 'Ӱ', 'ӱ', 'Ӳ', 'ӳ', 'Ӵ', 'ӵ',
+        'Ӷ', 'ӷ', 'Ӹ', 'ӹ', 'Ӻ', 'ӻ', 'Ӽ', 'ӽ', 'Ӿ', 'ӿ',
+    ],
+    include_bytes!("../res_rasterized_characters/unicode-cyrillic_h10_wBold.bin"),
+);
+
+/// The rasters of the wide chars of the unicode range `unicode-cyrillic`, which span two cells.
+#[cfg(feature = "unicode-cyrillic")]
+static UNICODE_CYRILLIC_WIDE: RasterTable = RasterTable::new(
+    &['Ѩ', 'Ѭ', 'ѭ', 'Ѹ', 'ѹ'],
+    include_bytes!("../res_rasterized_characters/unicode-cyrillic-wide_h10_wBold.bin"),
+);
+
+/// The rasters of the unicode range `unicode-latin-extended-additional`.
+#[cfg(feature = "unicode-latin-extended-additional")]
+static UNICODE_LATIN_EXTENDED_ADDITIONAL: RasterTable = RasterTable::new(
+    &[
+        'Ḁ', 'ḁ', 'Ḃ', 'ḃ', 'Ḅ', 'ḅ', 'Ḇ', 'ḇ', 'Ḉ', 'ḉ', 'Ḋ', 'ḋ', 'Ḍ', 'ḍ', 'Ḏ', 'ḏ', 'Ḑ', 'ḑ',
+        'Ḓ', 'ḓ', 'Ḕ', 'ḕ', 'Ḗ', 'ḗ', 'Ḙ', 'ḙ', 'Ḛ', 'ḛ', 'Ḝ', 'ḝ', 'Ḟ', 'ḟ', 'Ḡ', 'ḡ', 'Ḣ', 'ḣ',
+        'Ḥ', 'ḥ', 'Ḧ', 'ḧ', 'Ḩ', 'ḩ', 'Ḫ', 'ḫ', 'Ḭ', 'ḭ', 'Ḯ', 'ḯ', 'Ḱ', 'ḱ', 'Ḳ', 'ḳ', 'Ḵ', 'ḵ',
+        'Ḷ', 'ḷ', 'Ḹ', 'ḹ', 'Ḻ', 'ḻ', 'Ḽ', 'ḽ', 'Ḿ', 'ḿ', 'Ṁ', 'ṁ', 'Ṃ', 'ṃ', 'Ṅ', 'ṅ', 'Ṇ', 'ṇ',
+        'Ṉ', 'ṉ', 'Ṋ', 'ṋ', 'Ṍ', 'ṍ', 'Ṏ', 'ṏ', 'Ṑ', 'ṑ', 'Ṓ', 'ṓ', 'Ṕ', 'ṕ', 'Ṗ', 'ṗ', 'Ṙ', 'ṙ',
+        'Ṛ', 'ṛ', 'Ṝ', 'ṝ', 'Ṟ', 'ṟ', 'Ṡ', 'ṡ', 'Ṣ', 'ṣ', 'Ṥ', 'ṥ', 'Ṧ', 'ṧ', 'Ṩ', 'ṩ', 'Ṫ', 'ṫ',
+        'Ṭ', 'ṭ', 'Ṯ', 'ṯ', 'Ṱ', 'ṱ', 'Ṳ', 'ṳ', 'Ṵ', 'ṵ', 'Ṷ', 'ṷ', 'Ṹ', 'ṹ', 'Ṻ', 'ṻ', 'Ṽ', 'ṽ',
+        'Ṿ', 'ṿ', 'Ẁ', 'ẁ', 'Ẃ', 'ẃ', 'Ẅ', 'ẅ', 'Ẇ', 'ẇ', 'Ẉ', 'ẉ', 'Ẋ', 'ẋ', 'Ẍ', 'ẍ', 'Ẏ', 'ẏ',
+        'Ẑ', 'ẑ', 'Ẓ', 'ẓ', 'Ẕ', 'ẕ', 'ẖ', 'ẗ', 'ẘ', 'ẙ', 'ẚ', 'ẛ', 'ẜ', 'ẝ', 'ẞ', 'ẟ', 'Ạ', 'ạ',
+        'Ả', 'ả', 'Ấ', 'ấ', 'Ầ', 'ầ', 'Ẩ', 'ẩ', 'Ẫ', 'ẫ', 'Ậ', 'ậ', 'Ắ', 'ắ', 'Ằ', 'ằ', 'Ẳ', 'ẳ',
+        'Ẵ', 'ẵ', 'Ặ', 'ặ', 'Ẹ', 'ẹ', 'Ẻ', 'ẻ', 'Ẽ', 'ẽ', 'Ế', 'ế', 'Ề', 'ề', 'Ể', 'ể', 'Ễ', 'ễ',
+        'Ệ', 'ệ', 'Ỉ', 'ỉ', 'Ị', 'ị', 'Ọ', 'ọ', 'Ỏ', 'ỏ', 'Ố', 'ố', 'Ồ', 'ồ', 'Ổ', 'ổ', 'Ỗ', 'ỗ',
+        'Ộ', 'ộ', 'Ớ', 'ớ', 'Ờ', 'ờ', 'Ở', 'ở', 'Ỡ', 'ỡ', 'Ợ', 'ợ', 'Ụ', 'ụ', 'Ủ', 'ủ', 'Ứ', 'ứ',
+        'Ừ', 'ừ', 'Ử', 'ử', 'Ữ', 'ữ', 'Ự', 'ự', 'Ỳ', 'ỳ', 'Ỵ', 'ỵ', 'Ỷ', 'ỷ', 'Ỹ', 'ỹ', 'Ỻ', 'ỻ',
+        'Ỽ', 'ỽ', 'Ỿ', 'ỿ',
+    ],
+    include_bytes!("../res_rasterized_characters/unicode-latin-extended-additional_h10_wBold.bin"),
+);
+
+/// The rasters of the unicode range `unicode-general-punctuation`.
+#[cfg(feature = "unicode-general-punctuation")]
+static UNICODE_GENERAL_PUNCTUATION: RasterTable = RasterTable::new(
+    &[
+        ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', ' ', '‐', '‑', '‒', '–', '—', '―', '‖',
+        '‗', '‘', '’', '‚', '‛', '“', '”', '„', '‟', '†', '‡', '•', '‣', '․', '‥', '…', '‧', '‰',
+        '′', '″', '‴', '‵', '‶', '‷', '‸', '‹', '›', '‼', '‽', '‾', '‿', '⁀', '⁁', '⁃', '⁄', '⁅',
+        '⁆', '⁈', '⁉', '⁊', '⁋', '⁌', '⁍', '⁎', '⁏', '⁐', '⁑', '⁒', '⁔', '⁕', '⁖', '⁘', '⁙', '⁚',
+        '⁛', '⁜', '⁝', '⁞', ' ',
+    ],
+    include_bytes!("../res_rasterized_characters/unicode-general-punctuation_h10_wBold.bin"),
+);
+
+/// The rasters of the wide chars of the unicode range `unicode-general-punctuation`, which span two cells.
+#[cfg(feature = "unicode-general-punctuation")]
+static UNICODE_GENERAL_PUNCTUATION_WIDE: RasterTable = RasterTable::new(
+    &['‱', '※', '⁂', '⁇', '⁓', '⁗'],
+    include_bytes!("../res_rasterized_characters/unicode-general-punctuation-wide_h10_wBold.bin"),
+);
+
+/// The rasters of the unicode range `unicode-superscripts-and-subscripts`.
+#[cfg(feature = "unicode-superscripts-and-subscripts")]
+static UNICODE_SUPERSCRIPTS_AND_SUBSCRIPTS: RasterTable = RasterTable::new(
+    &[
+        '⁰', 'ⁱ', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹', '⁺', '⁻', '⁼', '⁽', '⁾', 'ⁿ', '₀', '₁', '₂', '₃',
+        '₄', '₅', '₆', '₇', '₈', '₉', '₊', '₋', '₌', '₍', '₎', 'ₐ', 'ₑ', 'ₒ', 'ₓ', 'ₔ', 'ₕ', 'ₖ',
+        'ₗ', 'ₘ', 'ₙ', 'ₚ', 'ₛ', 'ₜ',
+    ],
+    include_bytes!(
+        "../res_rasterized_characters/unicode-superscripts-and-subscripts_h10_wBold.bin"
+    ),
+);
+
+/// The rasters of the unicode range `unicode-currency-symbols`.
+#[cfg(feature = "unicode-currency-symbols")]
+static UNICODE_CURRENCY_SYMBOLS: RasterTable = RasterTable::new(
+    &[
+        '₠', '₡', '₢', '₣', '₤', '₥', '₦', '₧', '₩', '₪', '₫', '€', '₭', '₮', '₯', '₰', '₱', '₲',
+        '₳', '₴', '₵', '₶', '₸', '₹', '₺', '₻', '₼', '₽', '₾', '₿',
+    ],
+    include_bytes!("../res_rasterized_characters/unicode-currency-symbols_h10_wBold.bin"),
+);
+
+/// The rasters of the wide chars of the unicode range `unicode-currency-symbols`, which span two cells.
+#[cfg(feature = "unicode-currency-symbols")]
+static UNICODE_CURRENCY_SYMBOLS_WIDE: RasterTable = RasterTable::new(
+    &['₨', '₷'],
+    include_bytes!("../res_rasterized_characters/unicode-currency-symbols-wide_h10_wBold.bin"),
+);
+
+/// The rasters of the unicode range `unicode-letterlike-symbols`.
+#[cfg(feature = "unicode-letterlike-symbols")]
+static UNICODE_LETTERLIKE_SYMBOLS: RasterTable = RasterTable::new(
+    &[
+        '℀', '℁', 'ℂ', '℄', '℅', '℆', 'ℇ', '℈', 'ℊ', 'ℌ', 'ℍ', 'ℎ', 'ℏ', 'ℑ', 'ℓ', 'ℕ', '№', '℗',
+        'ℙ', 'ℚ', 'ℝ', '℞', '℟', '℠', '℡', '™', '℣', 'ℤ', '℥', 'Ω', '℧', 'ℨ', '℩', 'K', 'Å', 'ℭ',
+        '℮', 'ℯ', 'ℰ', 'Ⅎ', 'ℴ', 'ℵ', 'ℶ', 'ℷ', 'ℸ', 'ℹ', 'ℼ', 'ℽ', 'ℾ', 'ℿ', '⅀', '⅁', '⅂', '⅃',
+        '⅄', 'ⅅ', 'ⅆ', 'ⅇ', 'ⅈ', 'ⅉ', '⅊', '⅋', '⅌', '⅍', 'ⅎ',
+    ],
+    include_bytes!("../res_rasterized_characters/unicode-letterlike-symbols_h10_wBold.bin"),
+);
+
+/// The rasters of the wide chars of the unicode range `unicode-letterlike-symbols`, which span two cells.
+#[cfg(feature = "unicode-letterlike-symbols")]
+static UNICODE_LETTERLIKE_SYMBOLS_WIDE: RasterTable = RasterTable::new(
+    &[
+        '℃', '℉', 'ℋ', 'ℐ', 'ℒ', '℔', '℘', 'ℛ', 'ℜ', 'ℬ', 'ℱ', 'ℳ', '℺', '℻', '⅏',
+    ],
+    include_bytes!("../res_rasterized_characters/unicode-letterlike-symbols-wide_h10_wBold.bin"),
+);
+
+/// The rasters of the unicode range `unicode-number-forms`.
+#[cfg(feature = "unicode-number-forms")]
+static UNICODE_NUMBER_FORMS: RasterTable = RasterTable::new(
+    &['⅟', 'ↄ'],
+    include_bytes!("../res_rasterized_characters/unicode-number-forms_h10_wBold.bin"),
+);
+
+/// The rasters of the wide chars of the unicode range `unicode-number-forms`, which span two cells.
+#[cfg(feature = "unicode-number-forms")]
+static UNICODE_NUMBER_FORMS_WIDE: RasterTable = RasterTable::new(
+    &[
+        '⅐', '⅑', '⅒', '⅓', '⅔', '⅕', '⅖', '⅗', '⅘', '⅙', '⅚', '⅛', '⅜', '⅝', '⅞', '↉',
+    ],
+    include_bytes!("../res_rasterized_characters/unicode-number-forms-wide_h10_wBold.bin"),
+);
+
+/// The rasters of the unicode range `unicode-arrows`.
+#[cfg(feature = "unicode-arrows")]
+static UNICODE_ARROWS: RasterTable = RasterTable::new(
+    &[
+        '←', '↑', '→', '↓', '↔', '↕', '↜', '↝', '↞', '↠', '↢', '↣', '↤', '↦', '⇐', '⇑', '⇒', '⇓',
+        '⇔', '⇚', '⇛', '⇦', '⇨',
+    ],
+    include_bytes!("../res_rasterized_characters/unicode-arrows_h10_wBold.bin"),
+);
+
+/// The rasters of the unicode range `unicode-mathematical-operators`.
+#[cfg(feature = "unicode-mathematical-operators")]
+static UNICODE_MATHEMATICAL_OPERATORS: RasterTable = RasterTable::new(
+    &[
+        '∀', '∁', '∂', '∃', '∄', '∆', '∇', '∈', '∉', '∊', '∋', '∌', '∍', '∎', '∐', '−', '∘', '∙',
+        '√', '∞', '∠', '∣', '∧', '∨', '∩', '∪', '∴', '∵', '∶', '∷', '∸', '∼', '∽', '≁', '≃', '≅',
+        '≇', '≈', '≉', '≊', '≋', '≌', '≔', '≕', '≗', '≟', '≠', '≡', '≢', '≤', '≥', '≬', '≮', '≯',
+        '≰', '≱', '≲', '≳', '≴', '≵', '≺', '≻', '⊂', '⊃', '⊄', '⊅', '⊆', '⊇', '⊈', '⊉', '⊎', '⊑',
+        '⊒', '⊓', '⊔', '⊕', '⊖', '⊗', '⊘', '⊙', '⊚', '⊛', '⊜', '⊢', '⊣', '⊤', '⊥', '⊴', '⊵', '⊸',
+        '⋂', '⋃', '⋄', '⋆', '⋈', '⋉', '⋊', '⋍', '⋎', '⋐', '⋑', '⋢', '⋣',
+    ],
+    include_bytes!("../res_rasterized_characters/unicode-mathematical-operators_h10_wBold.bin"),
+);
+
+/// The rasters of the wide chars of the unicode range `unicode-mathematical-operators`, which span two cells.
+#[cfg(feature = "unicode-mathematical-operators")]
+static UNICODE_MATHEMATICAL_OPERATORS_WIDE: RasterTable = RasterTable::new(
+    &['∅'],
+    include_bytes!(
+        "../res_rasterized_characters/unicode-mathematical-operators-wide_h10_wBold.bin"
+    ),
+);
+
+/// The rasters of the unicode range `unicode-miscellaneous-technical`.
+#[cfg(feature = "unicode-miscellaneous-technical")]
+static UNICODE_MISCELLANEOUS_TECHNICAL: RasterTable = RasterTable::new(
+    &[
+        '⌀', '⌁', '⌂', '⌃', '⌄', '⌅', '⌆', '⌈', '⌉', '⌊', '⌋', '⌌', '⌍', '⌎', '⌏', '⌐', '⌑', '⌒',
+        '⌓', '⌔', '⌕', '⌘', '⌙', '⌜', '⌝', '⌞', '⌟', '⌠', '⌡', '⌥', '⌦', '⌧', '⌨', '⌫', '⌵', '⌶',
+        '⌷', '⌸', '⌹', '⌺', '⌻', '⌼', '⌽', '⌾', '⌿', '⍀', '⍁', '⍂', '⍃', '⍄', '⍅', '⍆', '⍇', '⍈',
+        '⍉', '⍊', '⍋', '⍌', '⍍', '⍎', '⍏', '⍐', '⍑', '⍒', '⍓', '⍔', '⍕', '⍖', '⍗', '⍘', '⍙', '⍛',
+        '⍜', '⍝', '⍞', '⍟', '⍠', '⍡', '⍢', '⍣', '⍤', '⍥', '⍦', '⍨', '⍩', '⍪', '⍫', '⍬', '⍭', '⍮',
+        '⍯', '⍰', '⍱', '⍲', '⍳', '⍴', '⍵', '⍶', '⍷', '⍸', '⍹', '⍺', '⍽', '⎀', '⎁', '⎂', '⎃', '⎈',
+        '⎉', '⎊', '⎋', '⎕', '⎛', '⎜', '⎝', '⎞', '⎟', '⎠', '⎡', '⎢', '⎣', '⎤', '⎥', '⎦', '⎧', '⎨',
+        '⎩', '⎪', '⎫', '⎬', '⎭', '⎮', '⎰', '⎱', '⎲', '⎳', '⎴', '⎵', '⎶', '⎷', '⎸', '⎹', '⎺', '⎻',
+        '⎼', '⎽', '⏎', '⏏',
+    ],
+    include_bytes!("../res_rasterized_characters/unicode-miscellaneous-technical_h10_wBold.bin"),
+);
+
+/// The rasters of the wide chars of the unicode range `unicode-miscellaneous-technical`, which span two cells.
+#[cfg(feature = "unicode-miscellaneous-technical")]
+static UNICODE_MISCELLANEOUS_TECHNICAL_WIDE: RasterTable = RasterTable::new(
+    &['⍚', '⍧', '⏜', '⏝', '⏞', '⏟', '⏠', '⏡'],
+    include_bytes!(
+        "../res_rasterized_characters/unicode-miscellaneous-technical-wide_h10_wBold.bin"
+    ),
+);
+
+/// The rasters of the unicode range `unicode-control-pictures`.
+#[cfg(feature = "unicode-control-pictures")]
+static UNICODE_CONTROL_PICTURES: RasterTable = RasterTable::new(
+    &[
+        '␀', '␁', '␂', '␃', '␄', '␅', '␆', '␇', '␈', '␉', '␊', '␋', '␌', '␍', '␎', '␏', '␐', '␑',
+        '␒', '␓', '␔', '␕', '␖', '␗', '␘', '␙', '␚', '␛', '␜', '␝', '␞', '␟', '␠', '␡', '␣', '␤',
+    ],
+    include_bytes!("../res_rasterized_characters/unicode-control-pictures_h10_wBold.bin"),
+);
+
+/// The rasters of the unicode range `unicode-box-drawing`.
+#[cfg(feature = "unicode-box-drawing")]
+static UNICODE_BOX_DRAWING: RasterTable = RasterTable::new(
+    &[
+        '─', '━', '│', '┃', '┄', '┅', '┆', '┇', '┈', '┉', '┊', '┋', '┌', '┍', '┎', '┏', '┐', '┑',
+        '┒', '┓', '└', '┕', '┖', '┗', '┘', '┙', '┚', '┛', '├', '┝', '┞', '┟', '┠', '┡', '┢', '┣',
+        '┤', '┥', '┦', '┧', '┨', '┩', '┪', '┫', '┬', '┭', '┮', '┯', '┰', '┱', '┲', '┳', '┴', '┵',
+        '┶', '┷', '┸', '┹', '┺', '┻', '┼', '┽', '┾', '┿', '╀', '╁', '╂', '╃', '╄', '╅', '╆', '╇',
+        '╈', '╉', '╊', '╋', '╌', '╍', '╎', '╏', '═', '║', '╒', '╓', '╔', '╕', '╖', '╗', '╘', '╙',
+        '╚', '╛', '╜', '╝', '╞', '╟', '╠', '╡', '╢', '╣', '╤', '╥', '╦', '╧', '╨', '╩', '╪', '╫',
+        '╬', '╭', '╮', '╯', '╰', '╱', '╲', '╳', '╴', '╵', '╶', '╷', '╸', '╹', '╺', '╻', '╼', '╽',
+        '╾', '╿',
+    ],
+    include_bytes!("../res_rasterized_characters/unicode-box-drawing_h10_wBold.bin"),
+);
+
+/// The rasters of the unicode range `unicode-block-elements`.
+#[cfg(feature = "unicode-block-elements")]
+static UNICODE_BLOCK_ELEMENTS: RasterTable = RasterTable::new(
+    &[
+        '▀', '▁', '▂', '▃', '▄', '▅', '▆', '▇', '█', '▉', '▊', '▋', '▌', '▍', '▎', '▏', '▐', '░',
+        '▒', '▓', '▔', '▕', '▖', '▗', '▘', '▙', '▚', '▛', '▜', '▝', '▞', '▟',
+    ],
+    include_bytes!("../res_rasterized_characters/unicode-block-elements_h10_wBold.bin"),
+);
+
+/// The rasters of the unicode range `unicode-geometric-shapes`.
+#[cfg(feature = "unicode-geometric-shapes")]
+static UNICODE_GEOMETRIC_SHAPES: RasterTable = RasterTable::new(
+    &[
+        '■', '□', '▢', '▣', '▤', '▥', '▦', '▧', '▨', '▩', '▪', '▫', '▬', '▮', '▯', '▲', '△', '▴',
+        '▵', '▶', '▷', '▸', '▹', '►', '▻', '▼', '▽', '▾', '▿', '◀', '◁', '◂', '◃', '◄', '◅', '◆',
+        '◇', '◈', '◉', '◊', '○', '◌', '◎', '●', '◐', '◑', '◒', '◓', '◔', '◕', '◖', '◗', '◘', '◙',
+        '◚', '◛', '◜', '◝', '◞', '◟', '◠', '◡', '◢', '◣', '◤', '◥', '◦', '◧', '◨', '◩', '◪', '◫',
+        '◬', '◭', '◮', '◰', '◱', '◲', '◳', '◴', '◵', '◶', '◷', '◸', '◹', '◺', '◻', '◼', '◽', '◾',
+        '◿',
+    ],
+    include_bytes!("../res_rasterized_characters/unicode-geometric-shapes_h10_wBold.bin"),
+);
+
+/// The rasters of the wide chars of the unicode range `unicode-geometric-shapes`, which span two cells.
+#[cfg(feature = "unicode-geometric-shapes")]
+static UNICODE_GEOMETRIC_SHAPES_WIDE: RasterTable = RasterTable::new(
+    &['▭', '▰', '▱', '◍', '◯'],
+    include_bytes!("../res_rasterized_characters/unicode-geometric-shapes-wide_h10_wBold.bin"),
+);
+
+/// The rasters of the unicode range `unicode-miscellaneous-symbols`.
+#[cfg(feature = "unicode-miscellaneous-symbols")]
+static UNICODE_MISCELLANEOUS_SYMBOLS: RasterTable = RasterTable::new(
+    &['★', '☆', '☐', '☑', '☒', '⚠'],
+    include_bytes!("../res_rasterized_characters/unicode-miscellaneous-symbols_h10_wBold.bin"),
+);
+
+/// The rasters of the unicode range `unicode-dingbats`.
+#[cfg(feature = "unicode-dingbats")]
+static UNICODE_DINGBATS: RasterTable = RasterTable::new(
+    &['✓', '✔', '✕', '✖', '✗', '✘'],
+    include_bytes!("../res_rasterized_characters/unicode-dingbats_h10_wBold.bin"),
+);
+
+/// The rasters of the unicode range `unicode-braille`.
+#[cfg(feature = "unicode-braille")]
+static UNICODE_BRAILLE: RasterTable = RasterTable::new(
+    &[
+        '⠀', '⠁', '⠂', '⠃', '⠄', '⠅', '⠆', '⠇', '⠈', '⠉', '⠊', '⠋', '⠌', '⠍', '⠎', '⠏', '⠐', '⠑',
+        '⠒', '⠓', '⠔', '⠕', '⠖', '⠗', '⠘', '⠙', '⠚', '⠛', '⠜', '⠝', '⠞', '⠟', '⠠', '⠡', '⠢', '⠣',
+        '⠤', '⠥', '⠦', '⠧', '⠨', '⠩', '⠪', '⠫', '⠬', '⠭', '⠮', '⠯', '⠰', '⠱', '⠲', '⠳', '⠴', '⠵',
+        '⠶', '⠷', '⠸', '⠹', '⠺', '⠻', '⠼', '⠽', '⠾', '⠿', '⡀', '⡁', '⡂', '⡃', '⡄', '⡅', '⡆', '⡇',
+        '⡈', '⡉', '⡊', '⡋', '⡌', '⡍', '⡎', '⡏', '⡐', '⡑', '⡒', '⡓', '⡔', '⡕', '⡖', '⡗', '⡘', '⡙',
+        '⡚', '⡛', '⡜', '⡝', '⡞', '⡟', '⡠', '⡡', '⡢', '⡣', '⡤', '⡥', '⡦', '⡧', '⡨', '⡩', '⡪', '⡫',
+        '⡬', '⡭', '⡮', '⡯', '⡰', '⡱', '⡲', '⡳', '⡴', '⡵', '⡶', '⡷', '⡸', '⡹', '⡺', '⡻', '⡼', '⡽',
+        '⡾', '⡿', '⢀', '⢁', '⢂', '⢃', '⢄', '⢅', '⢆', '⢇', '⢈', '⢉', '⢊', '⢋', '⢌', '⢍', '⢎', '⢏',
+        '⢐', '⢑', '⢒', '⢓', '⢔', '⢕', '⢖', '⢗', '⢘', '⢙', '⢚', '⢛', '⢜', '⢝', '⢞', '⢟', '⢠', '⢡',
+        '⢢', '⢣', '⢤', '⢥', '⢦', '⢧', '⢨', '⢩', '⢪', '⢫', '⢬', '⢭', '⢮', '⢯', '⢰', '⢱', '⢲', '⢳',
+        '⢴', '⢵', '⢶', '⢷', '⢸', '⢹', '⢺', '⢻', '⢼', '⢽', '⢾', '⢿', '⣀', '⣁', '⣂', '⣃', '⣄', '⣅',
+        '⣆', '⣇', '⣈', '⣉', '⣊', '⣋', '⣌', '⣍', '⣎', '⣏', '⣐', '⣑', '⣒', '⣓', '⣔', '⣕', '⣖', '⣗',
+        '⣘', '⣙', '⣚', '⣛', '⣜', '⣝', '⣞', '⣟', '⣠', '⣡', '⣢', '⣣', '⣤', '⣥', '⣦', '⣧', '⣨', '⣩',
+        '⣪', '⣫', '⣬', '⣭', '⣮', '⣯', '⣰', '⣱', '⣲', '⣳', '⣴', '⣵', '⣶', '⣷', '⣸', '⣹', '⣺', '⣻',
+        '⣼', '⣽', '⣾', '⣿',
+    ],
+    include_bytes!("../res_rasterized_characters/unicode-braille_h10_wBold.bin"),
+);
+
+/// The rasters of the wide chars of the unicode range `unicode-specials`, which span two cells.
+#[cfg(feature = "unicode-specials")]
+static UNICODE_SPECIALS_WIDE: RasterTable = RasterTable::new(
+    &['�'],
+    include_bytes!("../res_rasterized_characters/unicode-specials-wide_h10_wBold.bin"),
+);
+
+/// Returns the raster of the given character for font weight bold and font size 8px,
+/// row by row. Wide characters, such as '�', have a raster that spans two cells, i.e., it
+/// is twice as wide as [`RASTER_WIDTH`]. All characters are centered in their raster.
+#[inline]
+pub fn get_char(c: char) -> Option<&'static [u8]> {
+    let tables: &[&RasterTable] = &[
+        #[cfg(feature = "unicode-basic-latin")]
+        &UNICODE_BASIC_LATIN,
+        #[cfg(feature = "unicode-latin-1-supplement")]
+        &UNICODE_LATIN_1_SUPPLEMENT,
+        #[cfg(feature = "unicode-latin-extended-a")]
+        &UNICODE_LATIN_EXTENDED_A,
+        #[cfg(feature = "unicode-latin-extended-b")]
+        &UNICODE_LATIN_EXTENDED_B,
+        #[cfg(feature = "unicode-latin-extended-b")]
+        &UNICODE_LATIN_EXTENDED_B_WIDE,
+        #[cfg(feature = "unicode-ipa-extensions")]
+        &UNICODE_IPA_EXTENSIONS,
+        #[cfg(feature = "unicode-ipa-extensions")]
+        &UNICODE_IPA_EXTENSIONS_WIDE,
+        #[cfg(feature = "unicode-combining-diacritical-marks")]
+        &UNICODE_COMBINING_DIACRITICAL_MARKS,
+        #[cfg(feature = "unicode-greek")]
+        &UNICODE_GREEK,
+        #[cfg(feature = "unicode-cyrillic")]
+        &UNICODE_CYRILLIC,
+        #[cfg(feature = "unicode-cyrillic")]
+        &UNICODE_CYRILLIC_WIDE,
+        #[cfg(feature = "unicode-latin-extended-additional")]
+        &UNICODE_LATIN_EXTENDED_ADDITIONAL,
+        #[cfg(feature = "unicode-general-punctuation")]
+        &UNICODE_GENERAL_PUNCTUATION,
+        #[cfg(feature = "unicode-general-punctuation")]
+        &UNICODE_GENERAL_PUNCTUATION_WIDE,
+        #[cfg(feature = "unicode-superscripts-and-subscripts")]
+        &UNICODE_SUPERSCRIPTS_AND_SUBSCRIPTS,
+        #[cfg(feature = "unicode-currency-symbols")]
+        &UNICODE_CURRENCY_SYMBOLS,
+        #[cfg(feature = "unicode-currency-symbols")]
+        &UNICODE_CURRENCY_SYMBOLS_WIDE,
+        #[cfg(feature = "unicode-letterlike-symbols")]
+        &UNICODE_LETTERLIKE_SYMBOLS,
+        #[cfg(feature = "unicode-letterlike-symbols")]
+        &UNICODE_LETTERLIKE_SYMBOLS_WIDE,
+        #[cfg(feature = "unicode-number-forms")]
+        &UNICODE_NUMBER_FORMS,
+        #[cfg(feature = "unicode-number-forms")]
+        &UNICODE_NUMBER_FORMS_WIDE,
+        #[cfg(feature = "unicode-arrows")]
+        &UNICODE_ARROWS,
+        #[cfg(feature = "unicode-mathematical-operators")]
+        &UNICODE_MATHEMATICAL_OPERATORS,
+        #[cfg(feature = "unicode-mathematical-operators")]
+        &UNICODE_MATHEMATICAL_OPERATORS_WIDE,
+        #[cfg(feature = "unicode-miscellaneous-technical")]
+        &UNICODE_MISCELLANEOUS_TECHNICAL,
+        #[cfg(feature = "unicode-miscellaneous-technical")]
+        &UNICODE_MISCELLANEOUS_TECHNICAL_WIDE,
+        #[cfg(feature = "unicode-control-pictures")]
+        &UNICODE_CONTROL_PICTURES,
+        #[cfg(feature = "unicode-box-drawing")]
+        &UNICODE_BOX_DRAWING,
+        #[cfg(feature = "unicode-block-elements")]
+        &UNICODE_BLOCK_ELEMENTS,
+        #[cfg(feature = "unicode-geometric-shapes")]
+        &UNICODE_GEOMETRIC_SHAPES,
+        #[cfg(feature = "unicode-geometric-shapes")]
+        &UNICODE_GEOMETRIC_SHAPES_WIDE,
+        #[cfg(feature = "unicode-miscellaneous-symbols")]
+        &UNICODE_MISCELLANEOUS_SYMBOLS,
+        #[cfg(feature = "unicode-dingbats")]
+        &UNICODE_DINGBATS,
+        #[cfg(feature = "unicode-braille")]
+        &UNICODE_BRAILLE,
+        #[cfg(feature = "unicode-specials")]
+        &UNICODE_SPECIALS_WIDE,
+    ];
+    tables.iter().find_map(|table| table.get(c))
+}
@@ -0,0 +1,149 @@
+//! Helpers to draw dot graphics, such as sparklines and graphs, with braille patterns.
+//!
+//! Each braille pattern (U+2800 to U+28FF) has 2x4 dots. Hence, a console with `columns` x
+//! `rows` characters can show a dot bitmap of `columns * 2` x `rows * 4` dots. The dots of the
+//! rasters of this crate are placed on a consistent grid for each [`crate::RasterHeight`], so
+//! that the dots of adjacent characters line up.
+//!
+//! ```rust
+//! use noto_sans_mono_bitmap::braille::{braille_chars, char_from_dots};
+//!
+//! assert_eq!(char_from_dots([[true, false], [false, false], [false, false], [false, true]]), '⢁');
+//!
+//! // a diagonal line in a bitmap of 4x4 dots is drawn with two braille chars
+//! let chars = braille_chars(4, 4, |x, y| x == y);
+//! let line = chars.map(|(_column, _row, c)| c);
+//! assert!(line.eq(['⠑', '⢄']));
+//! ```
+
+// # THIS FILE GETS AUTO GENERATED BY THE PROJECT IN "../codegen" (see repository!)
+
+use core::fmt;
+
+/// Dots per braille char in horizontal direction.
+pub const DOTS_PER_CHAR_X: usize = 2;
+
+/// Dots per braille char in vertical direction.
+pub const DOTS_PER_CHAR_Y: usize = 4;
+
+/// The empty braille pattern. All other patterns are `BLANK + dots`.
+const BLANK: u32 = 0x2800;
+
+/// Returns the bit of a dot in the code point of a braille pattern. Dots 1 to 3 and 4 to 6
+/// are the first three rows of the left and the right column; dots 7 and 8 are the last row.
+const fn dot_bit(x: usize, y: usize) -> usize {
+    if y < 3 {
+        x * 3 + y
+    } else {
+        6 + x
+    }
+}
+
+/// Returns the braille char for a bitmask of dots in unicode order, i.e., bit 0 is dot 1
+/// (top left) and bit 7 is dot 8 (bottom right).
+#[inline]
+pub fn char_from_bitmask(dots: u8) -> char {
+    // all values from U+2800 to U+28FF are valid chars
+    char::from_u32(BLANK + dots as u32).unwrap_or(' ')
+}
+
+/// Returns the braille char for 2x4 dots. The dots are indexed as `dots[y][x]`.
+#[inline]
+pub fn char_from_dots(dots: [[bool; DOTS_PER_CHAR_X]; DOTS_PER_CHAR_Y]) -> char {
+    let mut bitmask = 0;
+    for (y, row) in dots.iter().enumerate() {
+        for (x, &dot) in row.iter().enumerate() {
+            if dot {
+                bitmask |= 1 << dot_bit(x, y);
+            }
+        }
+    }
+    char_from_bitmask(bitmask)
+}
+
+/// Returns an iterator over the braille chars that draw a bitmap of `width` x `height` dots.
+///
+/// The function `is_set(x, y)` tells whether a dot of the bitmap is set. The iterator yields
+/// the column, the row, and the char of each cell of the console grid, row by row. Cells at
+/// the right and the bottom border may be only partially covered by the bitmap.
+pub fn braille_chars<F: Fn(usize, usize) -> bool>(
+    width: usize,
+    height: usize,
+    is_set: F,
+) -> BrailleChars<F> {
+    BrailleChars {
+        columns: (width + DOTS_PER_CHAR_X - 1) / DOTS_PER_CHAR_X,
+        rows: (height + DOTS_PER_CHAR_Y - 1) / DOTS_PER_CHAR_Y,
+        width,
+        height,
+        is_set,
+        index: 0,
+    }
+}
+
+/// Iterator over the braille chars of a dot bitmap. See [`braille_chars`].
+pub struct BrailleChars<F> {
+    columns: usize,
+    rows: usize,
+    width: usize,
+    height: usize,
+    is_set: F,
+    // index of the next cell in the console grid
+    index: usize,
+}
+
+impl<F> BrailleChars<F> {
+    /// Number of columns of the console grid that the bitmap covers.
+    #[inline]
+    pub const fn columns(&self) -> usize {
+        self.columns
+    }
+
+    /// Number of rows of the console grid that the bitmap covers.
+    #[inline]
+    pub const fn rows(&self) -> usize {
+        self.rows
+    }
+}
+
+impl<F: Fn(usize, usize) -> bool> Iterator for BrailleChars<F> {
+    type Item = (usize, usize, char);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.columns * self.rows {
+            return None;
+        }
+        let column = self.index % self.columns;
+        let row = self.index / self.columns;
+        self.index += 1;
+
+        let mut dots = [[false; DOTS_PER_CHAR_X]; DOTS_PER_CHAR_Y];
+        for (dy, dots_row) in dots.iter_mut().enumerate() {
+            for (dx, dot) in dots_row.iter_mut().enumerate() {
+                let x = column * DOTS_PER_CHAR_X + dx;
+                let y = row * DOTS_PER_CHAR_Y + dy;
+                *dot = x < self.width && y < self.height && (self.is_set)(x, y);
+            }
+        }
+        Some((column, row, char_from_dots(dots)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.columns * self.rows - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<F> fmt::Debug for BrailleChars<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BrailleChars")
+            .field("columns", &self.columns)
+            .field("rows", &self.rows)
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .field("index", &self.index)
+            .finish()
+    }
+}
+
+// # THIS FILE GETS AUTO GENERATED BY THE PROJECT IN "../codegen" (see repository!)
@@ -0,0 +1,419 @@
+//! Composition of combining diacritical marks (U+0300 to U+036F) with their base char.
+//!
+//! Decomposed text (NFD), such as "e\u{301}" instead of "é", consists of a base char and one or
+//! more combining marks. The marks have rasters of their own, but they don't occupy a cell.
+//! Instead, they are drawn over the raster of the previous char. This also works for accented
+//! letters that have no precomposed raster in this crate, such as "q\u{301}".
+//!
+//! The rasters of the marks are placed for lowercase letters. Marks above the base are moved
+//! up if the base is taller, such as capital letters, and marks below the base are moved down
+//! for descenders, so that there is a gap of one pixel. The marks never leave the cell, though:
+//! if the cell has no space left, such as for a circumflex over 'ẞ' or a cedilla under 'g', the
+//! mark overlaps the base. Marks through the base, such as U+0336 (long stroke overlay), stay
+//! where they are. On wide base chars, the marks are centered horizontally.
+//!
+//! [`compose`] takes the rasters of this crate. [`compose_with`] takes the base chars and the
+//! marks from any [`GlyphSource`], e.g., to compose marks over own glyphs.
+//!
+//! ```rust
+//! use noto_sans_mono_bitmap::combining::compose;
+//! use noto_sans_mono_bitmap::{FontWeight, RasterHeight};
+//!
+//! let mut cells = compose("Ne\u{301}", FontWeight::Regular, RasterHeight::Size16);
+//! let (base, composed) = cells.next().unwrap();
+//! assert_eq!(base, 'N');
+//! assert_eq!(composed.unwrap().mark_count(), 0);
+//! // the mark doesn't take a cell of its own
+//! let (base, composed) = cells.next().unwrap();
+//! assert_eq!(base, 'e');
+//! let composed = composed.unwrap();
+//! assert_eq!(composed.mark_count(), 1);
+//! for y in 0..composed.height() {
+//!     for x in 0..composed.width() {
+//!         let _intensity = composed.pixel(x, y);
+//!         // draw the pixel
+//!     }
+//! }
+//! assert!(cells.next().is_none());
+//! ```
+
+// # THIS FILE GETS AUTO GENERATED BY THE PROJECT IN "../codegen" (see repository!)
+
+use crate::glyph_source::{Glyph, GlyphSource, NotoSansMono};
+use crate::{get_raster, get_raster_width, FontWeight, RasterHeight};
+use core::iter::Peekable;
+use core::str::Chars;
+
+/// Maximum number of marks on a single base char. Further marks are ignored.
+pub const MAX_MARKS: usize = 4;
+
+/// Returns true if the char is a combining diacritical mark (U+0300 to U+036F).
+#[inline]
+pub const fn is_combining_mark(c: char) -> bool {
+    matches!(c, '\u{300}'..='\u{36f}')
+}
+
+/// A base char with the combining marks that are drawn over it. Occupies the cells of the base
+/// char. The glyphs come from a [`GlyphSource`], by default the rasters of this crate.
+///
+/// The glyphs are looked up once, when the base char and the marks are added.
+#[derive(Debug)]
+pub struct ComposedChar<'a, S = NotoSansMono> {
+    base: char,
+    source: Source<'a, S>,
+    glyph: Glyph<'a>,
+    marks: [Option<Mark<'a>>; MAX_MARKS],
+    /// First and last row with visible pixels of the base char and the marks so far.
+    ink_rows: Option<(usize, usize)>,
+}
+
+/// A combining mark that is drawn over the base char.
+#[derive(Debug, Copy, Clone)]
+struct Mark<'a> {
+    glyph: Glyph<'a>,
+    /// Horizontal offset in pixels, to center the mark over wide base chars.
+    x: isize,
+    /// Vertical offset in pixels. Negative offsets move the mark up.
+    y: isize,
+}
+
+/// Where the glyphs of a [`ComposedChar`] come from. The rasters of this crate are `'static`, so
+/// they don't need a borrowed source.
+#[derive(Debug)]
+enum Source<'a, S> {
+    Builtin(NotoSansMono),
+    Borrowed(&'a S),
+}
+
+impl<'a, S: GlyphSource> Source<'a, S> {
+    fn glyph(&self, c: char) -> Option<Glyph<'a>> {
+        match *self {
+            Self::Builtin(font) => get_raster(c, font.style(), font.size()).map(Glyph::from),
+            Self::Borrowed(source) => source.glyph(c),
+        }
+    }
+}
+
+// not derived, as the sources don't need to be `Clone`
+impl<S> Clone for Source<'_, S> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S> Copy for Source<'_, S> {}
+
+impl<S> Clone for ComposedChar<'_, S> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S> Copy for ComposedChar<'_, S> {}
+
+impl ComposedChar<'static, NotoSansMono> {
+    /// Creates a composed char without marks. Returns `None`, if [`crate::get_raster`] doesn't
+    /// know the base char.
+    pub fn new(base: char, style: FontWeight, size: RasterHeight) -> Option<Self> {
+        Self::from_source(base, Source::Builtin(NotoSansMono::new(style, size)))
+    }
+}
+
+impl ComposedChar<'_, NotoSansMono> {
+    /// Number of cells that the base char occupies. The same as
+    /// [`crate::RasterizedChar::cell_width`].
+    #[inline]
+    pub const fn cell_width(&self) -> usize {
+        let font = match self.source {
+            Source::Builtin(font) => font,
+            Source::Borrowed(font) => *font,
+        };
+        self.glyph.width() / get_raster_width(font.style(), font.size())
+    }
+}
+
+// without trait bounds, as const fns with trait bounds need a newer Rust version than the MSRV
+impl<S> ComposedChar<'_, S> {
+    /// The base char.
+    #[inline]
+    pub const fn base(&self) -> char {
+        self.base
+    }
+
+    /// Width of the glyph of the base char, such as [`crate::RasterizedChar::width`].
+    #[inline]
+    pub const fn width(&self) -> usize {
+        self.glyph.width()
+    }
+
+    /// Height of the glyph of the base char, such as [`crate::RasterizedChar::height`].
+    #[inline]
+    pub const fn height(&self) -> usize {
+        self.glyph.height()
+    }
+}
+
+impl<'a, S: GlyphSource> ComposedChar<'a, S> {
+    /// Creates a composed char without marks, whose glyphs come from the given source. Returns
+    /// `None`, if the source doesn't know the base char.
+    pub fn with_source(base: char, source: &'a S) -> Option<Self> {
+        Self::from_source(base, Source::Borrowed(source))
+    }
+
+    fn from_source(base: char, source: Source<'a, S>) -> Option<Self> {
+        let glyph = source.glyph(base)?;
+        Some(Self {
+            base,
+            source,
+            glyph,
+            marks: [None; MAX_MARKS],
+            ink_rows: ink_rows(glyph),
+        })
+    }
+
+    /// Draws a combining mark over the base char and the previous marks. Returns false, if the
+    /// mark is not known by the source or if there are already [`MAX_MARKS`] marks.
+    pub fn add_mark(&mut self, mark: char) -> bool {
+        let slot = match self.marks.iter().position(Option::is_none) {
+            Some(slot) if is_combining_mark(mark) => slot,
+            _ => return false,
+        };
+        let glyph = match self.source.glyph(mark) {
+            Some(glyph) => glyph,
+            None => return false,
+        };
+        let mark_rows = ink_rows(glyph);
+        let (height, middle) = (self.height(), self.height() / 2);
+        let y = match (mark_rows, self.ink_rows) {
+            // above the base: move up until there is a gap of one pixel, but not out of the cell
+            (Some((mark_top, mark_bottom)), Some((base_top, _))) if mark_bottom < middle => {
+                let overlap = (mark_bottom + 2).saturating_sub(base_top);
+                -(overlap.min(mark_top) as isize)
+            }
+            // below the base: move down the same way
+            (Some((mark_top, mark_bottom)), Some((_, base_bottom))) if mark_top > middle => {
+                let overlap = (base_bottom + 2).saturating_sub(mark_top);
+                overlap.min(height.saturating_sub(1 + mark_bottom)) as isize
+            }
+            _ => 0,
+        };
+        let x = (self.width() as isize - glyph.width() as isize) / 2;
+        self.marks[slot] = Some(Mark { glyph, x, y });
+        if let Some((mark_top, mark_bottom)) = mark_rows {
+            let (mark_top, mark_bottom) = (
+                (mark_top as isize + y) as usize,
+                (mark_bottom as isize + y) as usize,
+            );
+            self.ink_rows = Some(match self.ink_rows {
+                Some((top, bottom)) => (top.min(mark_top), bottom.max(mark_bottom)),
+                None => (mark_top, mark_bottom),
+            });
+        }
+        true
+    }
+
+    /// Number of marks that are drawn over the base char.
+    pub fn mark_count(&self) -> usize {
+        self.marks.iter().flatten().count()
+    }
+
+    /// Returns the intensity of a pixel from 0 to 255, like [`crate::RasterizedChar::raster`].
+    /// Overlapping pixels of the base char and the marks have the maximum intensity of both.
+    pub fn pixel(&self, x: usize, y: usize) -> u8 {
+        self.marks
+            .iter()
+            .flatten()
+            .filter_map(|mark| {
+                let (x, y) = (x as isize - mark.x, y as isize - mark.y);
+                if x < 0 || y < 0 {
+                    return None;
+                }
+                Some(mark.glyph.pixel(x as usize, y as usize))
+            })
+            .fold(self.glyph.pixel(x, y), u8::max)
+    }
+}
+
+/// Returns the first and the last row of a glyph with visible pixels.
+fn ink_rows(glyph: Glyph) -> Option<(usize, usize)> {
+    let has_ink = |y: usize| (0..glyph.width()).any(|x| glyph.pixel(x, y) > 0);
+    let top = (0..glyph.height()).find(|&y| has_ink(y))?;
+    let bottom = (0..glyph.height()).rev().find(|&y| has_ink(y))?;
+    Some((top, bottom))
+}
+
+/// Returns an iterator over the cells of a text. Each cell consists of a base char and all
+/// combining marks that follow it. See the [module description](self).
+///
+/// The iterator yields the base char of each cell and its [`ComposedChar`], or `None`, if the
+/// base char is not known by [`crate::get_raster`]. Marks at the beginning of the text are drawn
+/// over a space. Wide base chars occupy two cells, see [`ComposedChar::cell_width`].
+pub fn compose(text: &str, style: FontWeight, size: RasterHeight) -> ComposedChars<'_, 'static> {
+    ComposedChars {
+        chars: text.chars().peekable(),
+        source: Source::Builtin(NotoSansMono::new(style, size)),
+    }
+}
+
+/// Like [`compose`], but takes the glyphs from the given source.
+///
+/// ```rust
+/// use noto_sans_mono_bitmap::combining::compose_with;
+/// use noto_sans_mono_bitmap::glyph_source::{GlyphSource, GlyphTable, NotoSansMono};
+/// use noto_sans_mono_bitmap::{FontWeight, RasterHeight};
+///
+/// const ROW: &[u8] = &[255; 9];
+/// static ICONS: GlyphTable = GlyphTable::new(&[('\u{e000}', &[ROW; 16])]);
+/// let font = ICONS.or(NotoSansMono::new(FontWeight::Regular, RasterHeight::Size16));
+///
+/// let (base, composed) = compose_with("\u{e000}\u{301}", &font).next().unwrap();
+/// assert_eq!(base, '\u{e000}');
+/// assert_eq!(composed.unwrap().mark_count(), 1);
+/// ```
+pub fn compose_with<'t, 's, S: GlyphSource>(
+    text: &'t str,
+    source: &'s S,
+) -> ComposedChars<'t, 's, S> {
+    ComposedChars {
+        chars: text.chars().peekable(),
+        source: Source::Borrowed(source),
+    }
+}
+
+/// Iterator over the cells of a text. See [`compose`] and [`compose_with`].
+#[derive(Debug)]
+pub struct ComposedChars<'t, 's, S = NotoSansMono> {
+    chars: Peekable<Chars<'t>>,
+    source: Source<'s, S>,
+}
+
+impl<'s, S: GlyphSource> Iterator for ComposedChars<'_, 's, S> {
+    type Item = (char, Option<ComposedChar<'s, S>>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let base = match self.chars.peek() {
+            Some(&c) if is_combining_mark(c) => ' ',
+            _ => self.chars.next()?,
+        };
+        let mut composed = ComposedChar::from_source(base, self.source);
+        while let Some(mark) = self.chars.next_if(|&c| is_combining_mark(c)) {
+            if let Some(composed) = composed.as_mut() {
+                composed.add_mark(mark);
+            }
+        }
+        Some((base, composed))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::glyph_source::GlyphTable;
+
+    const REGULAR_16: NotoSansMono = NotoSansMono::new(FontWeight::Regular, RasterHeight::Size16);
+
+    /// Returns the first and the last row of a mark with visible pixels, where it is drawn.
+    fn mark_rows(composed: &ComposedChar, index: usize) -> (usize, usize) {
+        let mark = composed.marks[index].unwrap();
+        let (top, bottom) = ink_rows(mark.glyph).unwrap();
+        (
+            (top as isize + mark.y) as usize,
+            (bottom as isize + mark.y) as usize,
+        )
+    }
+
+    #[test]
+    fn test_mark_over_capital() {
+        let mut small = ComposedChar::new('e', FontWeight::Regular, RasterHeight::Size16).unwrap();
+        assert!(small.add_mark('\u{301}'));
+        let mut capital =
+            ComposedChar::new('E', FontWeight::Regular, RasterHeight::Size16).unwrap();
+        assert!(capital.add_mark('\u{301}'));
+
+        // the mark is moved up above the capital letter, with a gap of one pixel if the cell has
+        // enough space
+        let (capital_top, _) = ink_rows(capital.glyph).unwrap();
+        let (mark_top, mark_bottom) = mark_rows(&capital, 0);
+        assert!(mark_bottom < capital_top);
+        assert!(mark_bottom + 2 <= capital_top || mark_top == 0);
+        assert!(mark_rows(&capital, 0) < mark_rows(&small, 0));
+    }
+
+    #[test]
+    fn test_mark_under_descender() {
+        let mut composed =
+            ComposedChar::new('q', FontWeight::Regular, RasterHeight::Size16).unwrap();
+        assert!(composed.add_mark('\u{323}'));
+        let (_, base_bottom) = ink_rows(composed.glyph).unwrap();
+        let (mark_top, mark_bottom) = mark_rows(&composed, 0);
+        // the mark is moved down as far as the cell allows
+        assert!(mark_top > base_bottom || mark_bottom == composed.height() - 1);
+    }
+
+    #[test]
+    fn test_marks_stay_in_cell() {
+        for (base, mark) in [('g', '\u{327}'), ('Q', '\u{302}'), ('j', '\u{331}')] {
+            let mut composed =
+                ComposedChar::new(base, FontWeight::Regular, RasterHeight::Size16).unwrap();
+            assert!(composed.add_mark(mark));
+            let (mark_top, mark_bottom) = mark_rows(&composed, 0);
+            assert!(mark_top <= mark_bottom && mark_bottom < composed.height());
+        }
+    }
+
+    #[test]
+    fn test_mark_on_wide_base() {
+        const ROW: &[u8] = &[0; 18];
+        let wide = GlyphTable::new(&[('\u{e000}', &[ROW; 16])]).or(REGULAR_16);
+        let mut composed = ComposedChar::with_source('\u{e000}', &wide).unwrap();
+        assert!(composed.add_mark('\u{301}'));
+
+        // the mark is centered: (18 - 9) / 2 pixels to the right
+        let mark = REGULAR_16.glyph('\u{301}').unwrap();
+        let y_offset = composed.marks[0].unwrap().y;
+        for y in 0..16 {
+            for x in 0..18 {
+                let (mark_x, mark_y) = (x as isize - 4, y as isize - y_offset);
+                let expected = if mark_x < 0 || mark_y < 0 {
+                    0
+                } else {
+                    mark.pixel(mark_x as usize, mark_y as usize)
+                };
+                assert_eq!(composed.pixel(x, y), expected, "{} {}", x, y);
+            }
+        }
+    }
+
+    #[test]
+    fn test_max_marks() {
+        let mut composed =
+            ComposedChar::new('a', FontWeight::Regular, RasterHeight::Size16).unwrap();
+        for _ in 0..MAX_MARKS {
+            assert!(composed.add_mark('\u{308}'));
+        }
+        assert!(!composed.add_mark('\u{301}'));
+        assert_eq!(composed.mark_count(), MAX_MARKS);
+
+        // further marks in a text are ignored, but don't take a cell
+        let text = "a\u{301}\u{302}\u{303}\u{304}\u{305}b";
+        let mut cells = compose(text, FontWeight::Regular, RasterHeight::Size16)
+            .map(|(base, composed)| (base, composed.unwrap().mark_count()));
+        assert_eq!(cells.next(), Some(('a', MAX_MARKS)));
+        assert_eq!(cells.next(), Some(('b', 0)));
+        assert_eq!(cells.next(), None);
+    }
+
+    #[test]
+    fn test_no_mark() {
+        let mut composed =
+            ComposedChar::new('a', FontWeight::Regular, RasterHeight::Size16).unwrap();
+        assert!(!composed.add_mark('b'));
+        assert_eq!(composed.mark_count(), 0);
+        assert_eq!(
+            composed.pixel(0, 0),
+            REGULAR_16.glyph('a').unwrap().pixel(0, 0)
+        );
+    }
+}
+
+// # THIS FILE GETS AUTO GENERATED BY THE PROJECT IN "../codegen" (see repository!)
@@ -0,0 +1,176 @@
+//! Rendering mode that makes control characters visible.
+//!
+//! [`crate::get_raster`] has no rasters for control characters, such as NUL, ESC, and DEL, and
+//! for invisible characters, such as the no-break space. When they appear in binary or corrupted
+//! output, they just disappear. [`get_raster_visible`] replaces them with their pictures from
+//! the Control Pictures block (U+2400 to U+243F) instead:
+//!
+//! | char              | shown as |
+//! |-------------------|----------|
+//! | U+0000 to U+001F  | '␀' to '␟' |
+//! | DEL (U+007F)      | '␡'      |
+//! | NBSP (U+00A0)     | '·'      |
+//! | SHY (U+00AD)      | '¬'      |
+//!
+//! NBSP and SHY are shown with chars of the `unicode-latin-1-supplement` feature.
+//!
+//! [`VisibleControls`] does the same for any [`GlyphSource`] and can be switched on and off at
+//! runtime, e.g., for a "show control characters" option of a console.
+//!
+//! ```rust
+//! use noto_sans_mono_bitmap::control_pictures::{get_raster_visible, visible_char};
+//! use noto_sans_mono_bitmap::{get_raster, FontWeight, RasterHeight};
+//!
+//! assert_eq!(visible_char('\x1b'), '␛');
+//! assert_eq!(visible_char('\u{a0}'), '·');
+//! assert_eq!(visible_char('A'), 'A');
+//!
+//! assert!(get_raster('\0', FontWeight::Regular, RasterHeight::Size16).is_none());
+//! assert!(get_raster_visible('\0', FontWeight::Regular, RasterHeight::Size16).is_some());
+//! ```
+//!
+//! ```rust
+//! use noto_sans_mono_bitmap::control_pictures::VisibleControls;
+//! use noto_sans_mono_bitmap::glyph_source::{GlyphSource, NotoSansMono};
+//! use noto_sans_mono_bitmap::{FontWeight, RasterHeight};
+//!
+//! let source = NotoSansMono::new(FontWeight::Regular, RasterHeight::Size16);
+//! let mut font = VisibleControls::new(source);
+//! assert!(font.glyph('\x1b').is_some());
+//! font.set_enabled(false);
+//! assert!(font.glyph('\x1b').is_none());
+//! ```
+
+// # THIS FILE GETS AUTO GENERATED BY THE PROJECT IN "../codegen" (see repository!)
+
+use crate::glyph_source::{Glyph, GlyphSource};
+use crate::{FontWeight, RasterHeight, RasterizedChar};
+
+/// Code point of '␀', the picture of the first control character. The pictures of all other
+/// C0 control characters follow in the same order.
+const CONTROL_PICTURES_BEGIN: u32 = 0x2400;
+
+/// Returns the char that makes a control character or an invisible character visible. All
+/// other chars are returned unchanged. See the [module description](self).
+///
+/// Line breaks are mapped as well. Consoles that want to keep their line breaks should handle
+/// '\n' before they call this function.
+#[inline]
+pub fn visible_char(c: char) -> char {
+    match c {
+        '\0'..='\x1f' => char::from_u32(CONTROL_PICTURES_BEGIN + c as u32).unwrap_or(c),
+        '\x7f' => '␡',
+        '\u{a0}' => '·',
+        '\u{ad}' => '¬',
+        _ => c,
+    }
+}
+
+/// Like [`crate::get_raster`], but returns the raster of the [`visible_char`] of the char.
+#[inline]
+pub fn get_raster_visible(
+    c: char,
+    style: FontWeight,
+    size: RasterHeight,
+) -> Option<RasterizedChar> {
+    crate::get_raster(visible_char(c), style, size)
+}
+
+/// Source that shows control characters and invisible characters of another source as their
+/// [`visible_char`], as long as it is enabled. It is enabled by default.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct VisibleControls<S> {
+    source: S,
+    enabled: bool,
+}
+
+impl<S> VisibleControls<S> {
+    /// Creates an enabled source that looks up the glyphs in `source`.
+    pub const fn new(source: S) -> Self {
+        Self {
+            source,
+            enabled: true,
+        }
+    }
+
+    /// Whether control characters are shown as their pictures.
+    #[inline]
+    pub const fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Switches between showing control characters as their pictures and passing all chars to
+    /// the inner source unchanged.
+    #[inline]
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    /// The inner source.
+    #[inline]
+    pub const fn source(&self) -> &S {
+        &self.source
+    }
+}
+
+impl<S: GlyphSource> GlyphSource for VisibleControls<S> {
+    fn glyph(&self, c: char) -> Option<Glyph<'_>> {
+        if self.enabled {
+            self.source.glyph(visible_char(c))
+        } else {
+            self.source.glyph(c)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::glyph_source::{GlyphTable, NotoSansMono};
+
+    const REGULAR_16: NotoSansMono = NotoSansMono::new(FontWeight::Regular, RasterHeight::Size16);
+
+    #[test]
+    fn test_visible_char() {
+        assert_eq!(visible_char('\0'), '␀');
+        assert_eq!(visible_char('\n'), '␊');
+        assert_eq!(visible_char('\x1f'), '␟');
+        assert_eq!(visible_char('\x7f'), '␡');
+        assert_eq!(visible_char(' '), ' ');
+        assert_eq!(visible_char('\u{80}'), '\u{80}');
+    }
+
+    #[test]
+    fn test_visible_controls_enabled() {
+        let font = VisibleControls::new(REGULAR_16);
+        assert!(font.enabled());
+        assert_eq!(font.glyph('\x1b'), REGULAR_16.glyph('␛'));
+        assert!(font.glyph('\x1b').is_some());
+        assert_eq!(font.glyph('A'), REGULAR_16.glyph('A'));
+    }
+
+    #[test]
+    fn test_visible_controls_disabled() {
+        let mut font = VisibleControls::new(REGULAR_16);
+        font.set_enabled(false);
+        assert!(!font.enabled());
+        assert!(font.glyph('\x1b').is_none());
+        assert_eq!(font.glyph('A'), REGULAR_16.glyph('A'));
+
+        font.set_enabled(true);
+        assert!(font.glyph('\x1b').is_some());
+    }
+
+    #[test]
+    fn test_visible_controls_own_source() {
+        // a source with an own glyph for ESC keeps it while control pictures are disabled
+        static ESC: GlyphTable = GlyphTable::new(&[('\x1b', &[&[255]])]);
+        let mut font = VisibleControls::new(ESC.or(REGULAR_16));
+        assert_eq!(font.glyph('\x1b'), REGULAR_16.glyph('␛'));
+        font.set_enabled(false);
+        assert_eq!(font.glyph('\x1b').map(|glyph| glyph.pixel(0, 0)), Some(255));
+        assert!(font.source().glyph('\0').is_none());
+    }
+}
+
+// # THIS FILE GETS AUTO GENERATED BY THE PROJECT IN "../codegen" (see repository!)
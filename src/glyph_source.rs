@@ -0,0 +1,442 @@
+//! Abstraction over sources of glyphs, to mix this font with own glyphs and other bitmap fonts.
+//!
+//! A [`GlyphSource`] looks up the [`Glyph`] of a char. The rasters of this crate are available
+//! as [`NotoSansMono`] for a [`FontWeight`] and a [`RasterHeight`]. Own glyphs, such as a logo,
+//! icons, or hand-drawn symbols, can be put into a [`GlyphTable`], which is const-constructible
+//! and can live in a `static`. [`GlyphSource::or`] chains several sources, which are tried in
+//! order. Arrays and slices of sources are tried in order as well. Linux console fonts can be
+//! loaded with [`crate::psf::PsfFont`].
+//!
+//! Code that draws text should accept any `GlyphSource` instead of calling
+//! [`crate::get_raster`], so that users can plug in their own glyphs.
+//!
+//! ```rust
+//! use noto_sans_mono_bitmap::glyph_source::{GlyphSource, GlyphTable, NotoSansMono};
+//! use noto_sans_mono_bitmap::{FontWeight, RasterHeight};
+//!
+//! // a 2x2 logo in the private use area
+//! static LOGO: GlyphTable = GlyphTable::new(&[('\u{e000}', &[&[0, 255], &[255, 0]])]);
+//!
+//! let font = LOGO.or(NotoSansMono::new(FontWeight::Regular, RasterHeight::Size16));
+//! let logo = font.glyph('\u{e000}').unwrap();
+//! assert_eq!((logo.width(), logo.height()), (2, 2));
+//! assert_eq!(logo.pixel(1, 0), 255);
+//! assert_eq!(font.glyph('A').unwrap().height(), 16);
+//! assert!(font.glyph('\u{e001}').is_none());
+//! ```
+
+// # THIS FILE GETS AUTO GENERATED BY THE PROJECT IN "../codegen" (see repository!)
+
+use crate::hex_box::HexBox;
+use crate::{get_raster, FontWeight, RasterHeight, RasterizedChar};
+
+/// Looks up the glyph of a char. See the [module description](self).
+pub trait GlyphSource {
+    /// Returns the glyph of the char, or `None`, if the source doesn't know the char.
+    fn glyph(&self, c: char) -> Option<Glyph<'_>>;
+
+    /// Returns a source that tries this source first and `other` afterwards.
+    fn or<S: GlyphSource>(self, other: S) -> Chain<Self, S>
+    where
+        Self: Sized,
+    {
+        Chain {
+            first: self,
+            second: other,
+        }
+    }
+}
+
+/// A borrowed raster of a char.
+///
+/// Pixels have an intensity from 0 to 255, like [`RasterizedChar::raster`]. The raster either
+/// has one byte per pixel, in rows or packed like the rasters of this crate, or, as in bitmap
+/// fonts such as PSF, one bit per pixel, which is expanded to 0 or 255. A [`HexBox`] is a glyph
+/// as well.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Glyph<'a> {
+    data: GlyphData<'a>,
+    width: usize,
+    height: usize,
+}
+
+/// Storage of the pixels of a [`Glyph`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum GlyphData<'a> {
+    /// One intensity per pixel.
+    Rows(&'a [&'a [u8]]),
+    /// One intensity per pixel, all rows one after another.
+    Pixels(&'a [u8]),
+    /// One bit per pixel, the most significant bit first. Each row is padded to whole bytes.
+    Bits(&'a [u8]),
+    /// Calculated on request.
+    HexBox(HexBox),
+}
+
+impl<'a> Glyph<'a> {
+    /// Creates a glyph from its rows. The width is the length of the first row.
+    pub const fn new(raster: &'a [&'a [u8]]) -> Self {
+        let width = if raster.is_empty() {
+            0
+        } else {
+            raster[0].len()
+        };
+        Self {
+            data: GlyphData::Rows(raster),
+            width,
+            height: raster.len(),
+        }
+    }
+
+    /// Creates a glyph from its pixels with one intensity per pixel, row by row, like
+    /// [`crate::Raster::pixels`].
+    pub const fn from_pixels(pixels: &'a [u8], width: usize, height: usize) -> Self {
+        Self {
+            data: GlyphData::Pixels(pixels),
+            width,
+            height,
+        }
+    }
+
+    /// Creates a glyph from a bitmap with one bit per pixel, the most significant bit first.
+    /// Each row is padded to whole bytes. Set bits have the intensity 255.
+    pub const fn from_bits(bitmap: &'a [u8], width: usize, height: usize) -> Self {
+        Self {
+            data: GlyphData::Bits(bitmap),
+            width,
+            height,
+        }
+    }
+
+    /// The rows of the glyph, if it was created from rows, such as the glyphs of a
+    /// [`GlyphTable`]. Other glyphs return `None`, use [`Self::row`] or [`Self::pixel`] for
+    /// them.
+    #[inline]
+    pub const fn raster(&self) -> Option<&'a [&'a [u8]]> {
+        match self.data {
+            GlyphData::Rows(raster) => Some(raster),
+            GlyphData::Pixels(_) | GlyphData::Bits(_) | GlyphData::HexBox(_) => None,
+        }
+    }
+
+    /// Returns a row of the glyph with one intensity per pixel, such as a row of the rasters of
+    /// this crate or of a [`GlyphTable`]. Returns `None` for rows outside of the raster and for
+    /// glyphs with one bit per pixel or hex boxes, use [`Self::pixel`] for them.
+    pub fn row(&self, y: usize) -> Option<&'a [u8]> {
+        match self.data {
+            GlyphData::Rows(raster) => raster.get(y).copied(),
+            GlyphData::Pixels(_) if y >= self.height => None,
+            GlyphData::Pixels(pixels) => pixels.get(y * self.width..(y + 1) * self.width),
+            GlyphData::Bits(_) | GlyphData::HexBox(_) => None,
+        }
+    }
+
+    /// Width of the raster.
+    #[inline]
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Height of the raster.
+    #[inline]
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the intensity of a pixel from 0 to 255. Pixels outside of the raster are 0.
+    pub fn pixel(&self, x: usize, y: usize) -> u8 {
+        match self.data {
+            GlyphData::Rows(raster) => raster
+                .get(y)
+                .and_then(|row| row.get(x))
+                .copied()
+                .unwrap_or(0),
+            GlyphData::Pixels(_) | GlyphData::Bits(_) if x >= self.width || y >= self.height => 0,
+            GlyphData::Pixels(pixels) => pixels.get(y * self.width + x).copied().unwrap_or(0),
+            GlyphData::Bits(bitmap) => {
+                let bytes_per_row = (self.width + 7) / 8;
+                match bitmap.get(y * bytes_per_row + x / 8) {
+                    Some(byte) if byte & (0x80 >> (x % 8)) != 0 => 0xff,
+                    _ => 0,
+                }
+            }
+            GlyphData::HexBox(hex_box) => hex_box.pixel(x, y),
+        }
+    }
+}
+
+impl From<RasterizedChar> for Glyph<'static> {
+    fn from(raster: RasterizedChar) -> Self {
+        Self {
+            data: GlyphData::Pixels(raster.raster().pixels()),
+            width: raster.width(),
+            height: raster.height(),
+        }
+    }
+}
+
+impl From<HexBox> for Glyph<'_> {
+    fn from(hex_box: HexBox) -> Self {
+        Self {
+            data: GlyphData::HexBox(hex_box),
+            width: hex_box.width(),
+            height: hex_box.height(),
+        }
+    }
+}
+
+/// The rasters of this crate for a font weight and a raster height, as in [`get_raster`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct NotoSansMono {
+    style: FontWeight,
+    size: RasterHeight,
+}
+
+impl NotoSansMono {
+    /// Creates the source for the given [`FontWeight`] and [`RasterHeight`].
+    pub const fn new(style: FontWeight, size: RasterHeight) -> Self {
+        Self { style, size }
+    }
+
+    /// The font weight of the source.
+    #[inline]
+    pub const fn style(&self) -> FontWeight {
+        self.style
+    }
+
+    /// The raster height of the source.
+    #[inline]
+    pub const fn size(&self) -> RasterHeight {
+        self.size
+    }
+}
+
+impl GlyphSource for NotoSansMono {
+    fn glyph(&self, c: char) -> Option<Glyph<'_>> {
+        get_raster(c, self.style, self.size).map(Glyph::from)
+    }
+}
+
+/// A table of own glyphs, such as a logo or icons. The glyphs don't need to have the size of
+/// the rasters of this crate. Lookups search the table from the beginning, so the first entry
+/// of a char wins.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct GlyphTable<'a> {
+    glyphs: &'a [(char, &'a [&'a [u8]])],
+}
+
+impl<'a> GlyphTable<'a> {
+    /// Creates a table from pairs of a char and its raster.
+    pub const fn new(glyphs: &'a [(char, &'a [&'a [u8]])]) -> Self {
+        Self { glyphs }
+    }
+
+    /// The pairs of a char and its raster.
+    #[inline]
+    pub const fn glyphs(&self) -> &'a [(char, &'a [&'a [u8]])] {
+        self.glyphs
+    }
+}
+
+impl GlyphSource for GlyphTable<'_> {
+    fn glyph(&self, c: char) -> Option<Glyph<'_>> {
+        self.glyphs
+            .iter()
+            .find(|(glyph_c, _)| *glyph_c == c)
+            .map(|(_, raster)| Glyph::new(raster))
+    }
+}
+
+/// Source that tries two sources in order. See [`GlyphSource::or`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Chain<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<A: GlyphSource, B: GlyphSource> GlyphSource for Chain<A, B> {
+    fn glyph(&self, c: char) -> Option<Glyph<'_>> {
+        self.first.glyph(c).or_else(|| self.second.glyph(c))
+    }
+}
+
+impl<S: GlyphSource + ?Sized> GlyphSource for &S {
+    fn glyph(&self, c: char) -> Option<Glyph<'_>> {
+        (**self).glyph(c)
+    }
+}
+
+impl<S: GlyphSource> GlyphSource for [S] {
+    fn glyph(&self, c: char) -> Option<Glyph<'_>> {
+        self.iter().find_map(|source| source.glyph(c))
+    }
+}
+
+impl<S: GlyphSource, const N: usize> GlyphSource for [S; N] {
+    fn glyph(&self, c: char) -> Option<Glyph<'_>> {
+        self[..].glyph(c)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::get_raster_width;
+    use core::cell::Cell;
+
+    const REGULAR_16: NotoSansMono = NotoSansMono::new(FontWeight::Regular, RasterHeight::Size16);
+
+    /// A source that knows a single char and counts how often it is asked.
+    struct CountingSource {
+        c: char,
+        raster: &'static [&'static [u8]],
+        lookups: Cell<usize>,
+    }
+
+    impl CountingSource {
+        const fn new(c: char, raster: &'static [&'static [u8]]) -> Self {
+            Self {
+                c,
+                raster,
+                lookups: Cell::new(0),
+            }
+        }
+    }
+
+    impl GlyphSource for CountingSource {
+        fn glyph(&self, c: char) -> Option<Glyph<'_>> {
+            self.lookups.set(self.lookups.get() + 1);
+            (c == self.c).then(|| Glyph::new(self.raster))
+        }
+    }
+
+    #[test]
+    fn test_chain_order() {
+        let first = CountingSource::new('A', &[&[1]]);
+        let second = CountingSource::new('A', &[&[2]]);
+        let chain = (&first).or(&second);
+
+        // the first source wins, the second one isn't asked
+        assert_eq!(chain.glyph('A').unwrap().pixel(0, 0), 1);
+        assert_eq!((first.lookups.get(), second.lookups.get()), (1, 0));
+
+        // the second source is only asked on a miss of the first one
+        assert!(chain.glyph('B').is_none());
+        assert_eq!((first.lookups.get(), second.lookups.get()), (2, 1));
+    }
+
+    #[test]
+    fn test_slices_and_arrays() {
+        let sources = [
+            GlyphTable::new(&[('A', &[&[1]])]),
+            GlyphTable::new(&[('A', &[&[2]]), ('B', &[&[3]])]),
+        ];
+        assert_eq!(sources.glyph('A').unwrap().pixel(0, 0), 1);
+        assert_eq!(sources.glyph('B').unwrap().pixel(0, 0), 3);
+        assert!(sources.glyph('C').is_none());
+
+        let slice: &[GlyphTable] = &sources[1..];
+        assert_eq!(slice.glyph('A').unwrap().pixel(0, 0), 2);
+        let empty: &[GlyphTable] = &[];
+        assert!(empty.glyph('A').is_none());
+    }
+
+    #[test]
+    fn test_glyph_table_first_entry_wins() {
+        let table = GlyphTable::new(&[('A', &[&[1, 2]]), ('A', &[&[3]])]);
+        let glyph = table.glyph('A').unwrap();
+        assert_eq!((glyph.width(), glyph.height()), (2, 1));
+        assert_eq!(glyph.raster(), Some(&[&[1_u8, 2][..]][..]));
+        assert_eq!(table.glyphs().len(), 2);
+    }
+
+    #[test]
+    fn test_from_pixels() {
+        let glyph = Glyph::from_pixels(&[1, 2, 3, 4, 5, 6], 3, 2);
+        assert_eq!((glyph.width(), glyph.height()), (3, 2));
+        assert_eq!(
+            (glyph.pixel(0, 0), glyph.pixel(2, 0), glyph.pixel(0, 1)),
+            (1, 3, 4)
+        );
+        assert_eq!(glyph.row(1), Some(&[4, 5, 6][..]));
+        assert_eq!(glyph.raster(), None);
+        // out of bounds, although the pixel after the end of a row exists in the data
+        assert_eq!((glyph.pixel(3, 0), glyph.pixel(0, 2)), (0, 0));
+        assert_eq!(glyph.row(2), None);
+
+        // too little data
+        let glyph = Glyph::from_pixels(&[1, 2, 3, 4], 3, 2);
+        assert_eq!((glyph.pixel(0, 1), glyph.pixel(1, 1)), (4, 0));
+        assert_eq!(glyph.row(1), None);
+    }
+
+    #[test]
+    fn test_from_bits() {
+        // 10 pixels per row, which are padded to two bytes
+        let glyph = Glyph::from_bits(&[0b1000_0000, 0b0100_0000, 0xff, 0xc0], 10, 2);
+        assert_eq!((glyph.width(), glyph.height()), (10, 2));
+        assert_eq!(
+            (glyph.pixel(0, 0), glyph.pixel(1, 0), glyph.pixel(9, 0)),
+            (255, 0, 255)
+        );
+        assert!((0..10).all(|x| glyph.pixel(x, 1) == 255));
+        // the padding bits of a row are out of bounds
+        assert_eq!((glyph.pixel(10, 1), glyph.pixel(15, 1)), (0, 0));
+        assert_eq!(
+            (glyph.pixel(0, 2), glyph.row(0), glyph.raster()),
+            (0, None, None)
+        );
+
+        // too little data
+        let glyph = Glyph::from_bits(&[0xff], 8, 2);
+        assert_eq!((glyph.pixel(7, 0), glyph.pixel(0, 1)), (255, 0));
+    }
+
+    #[test]
+    fn test_new_rows() {
+        let glyph = Glyph::new(&[&[1, 2], &[3, 4]]);
+        assert_eq!((glyph.width(), glyph.height()), (2, 2));
+        assert_eq!(glyph.row(1), Some(&[3, 4][..]));
+        assert_eq!(
+            (glyph.pixel(1, 1), glyph.pixel(2, 0), glyph.pixel(0, 2)),
+            (4, 0, 0)
+        );
+        let empty = Glyph::new(&[]);
+        assert_eq!(
+            (empty.width(), empty.height(), empty.pixel(0, 0)),
+            (0, 0, 0)
+        );
+    }
+
+    #[test]
+    fn test_from_rasterized_char() {
+        let raster = get_raster('A', FontWeight::Regular, RasterHeight::Size16).unwrap();
+        let glyph = REGULAR_16.glyph('A').unwrap();
+        assert_eq!((glyph.width(), glyph.height()), (raster.width(), 16));
+        for y in 0..16 {
+            assert_eq!(glyph.row(y), raster.raster().get(y));
+        }
+        assert!(REGULAR_16.glyph('\u{e000}').is_none());
+    }
+
+    #[cfg(feature = "unicode-specials")]
+    #[test]
+    fn test_from_wide_rasterized_char() {
+        let raster = get_raster('�', FontWeight::Regular, RasterHeight::Size16).unwrap();
+        assert_eq!(raster.cell_width(), 2);
+        let glyph =
+            Glyph::from(get_raster('�', FontWeight::Regular, RasterHeight::Size16).unwrap());
+        assert_eq!(
+            glyph.width(),
+            2 * get_raster_width(FontWeight::Regular, RasterHeight::Size16)
+        );
+        for y in 0..16 {
+            assert_eq!(glyph.row(y), raster.raster().get(y));
+            for x in 0..glyph.width() {
+                assert_eq!(glyph.pixel(x, y), raster.raster()[y][x]);
+            }
+        }
+    }
+}
+
+// # THIS FILE GETS AUTO GENERATED BY THE PROJECT IN "../codegen" (see repository!)
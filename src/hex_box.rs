@@ -0,0 +1,484 @@
+//! Fallback glyphs that show the code point of a char in a box, such as GNU Unifont does.
+//!
+//! For chars that [`crate::get_raster`] doesn't know, a [`HexBox`] shows the code point as hex
+//! digits in a box, instead of a generic '?' or '�'. This way, one can tell which char is
+//! missing. Chars of the Basic Multilingual Plane show four digits in two rows, all other chars
+//! show six digits. The digits are cut out of a filled box and come from a tiny built-in 3x5
+//! pixel font, which is scaled by whole pixels to the raster of the current [`RasterHeight`].
+//!
+//! If the digits don't fit into a single cell, the box occupies two cells, like wide chars do
+//! (see [`crate::RasterizedChar::cell_width`]). For the smallest raster heights, the digits come
+//! from an even tinier 2x3 pixel font, and the margin between the digits and the edge of the box
+//! is left out. If the digits don't fit at all, only the frame of the box is shown.
+//!
+//! A [`HexBox`] doesn't need any allocation, as it calculates each pixel on request.
+//! [`HexBoxSource`] is a [`GlyphSource`] that returns a hex box for every char, e.g., as the
+//! last source of a chain.
+//!
+//! ```rust
+//! use noto_sans_mono_bitmap::glyph_source::{GlyphSource, NotoSansMono};
+//! use noto_sans_mono_bitmap::hex_box::{HexBox, HexBoxSource};
+//! use noto_sans_mono_bitmap::{get_raster, FontWeight, RasterHeight};
+//!
+//! let (c, style, size) = ('\u{e000}', FontWeight::Regular, RasterHeight::Size16);
+//! assert!(get_raster(c, style, size).is_none());
+//!
+//! let hex_box = HexBox::for_raster(c, style, size);
+//! for y in 0..hex_box.height() {
+//!     for x in 0..hex_box.width() {
+//!         let _intensity = hex_box.pixel(x, y);
+//!         // draw the pixel
+//!     }
+//! }
+//!
+//! // emojis show six digits in two cells
+//! assert_eq!(HexBox::for_raster('😀', style, size).cell_width(), 2);
+//!
+//! let font = NotoSansMono::new(style, size).or(HexBoxSource::for_raster(style, size));
+//! assert_eq!(font.glyph(c).unwrap().pixel(0, 0), hex_box.pixel(0, 0));
+//! ```
+
+// # THIS FILE GETS AUTO GENERATED BY THE PROJECT IN "../codegen" (see repository!)
+
+use crate::glyph_source::{Glyph, GlyphSource};
+use crate::{get_raster_width, FontWeight, RasterHeight};
+
+/// Hex digits 0 to F with 3x5 pixels. Each row is a bitmask where bit 2 is the left pixel.
+const BIG_DIGITS: [[u8; 5]; 16] = [
+    [0b111, 0b101, 0b101, 0b101, 0b111],
+    [0b010, 0b110, 0b010, 0b010, 0b111],
+    [0b111, 0b001, 0b111, 0b100, 0b111],
+    [0b111, 0b001, 0b111, 0b001, 0b111],
+    [0b101, 0b101, 0b111, 0b001, 0b001],
+    [0b111, 0b100, 0b111, 0b001, 0b111],
+    [0b111, 0b100, 0b111, 0b101, 0b111],
+    [0b111, 0b001, 0b001, 0b001, 0b001],
+    [0b111, 0b101, 0b111, 0b101, 0b111],
+    [0b111, 0b101, 0b111, 0b001, 0b111],
+    [0b111, 0b101, 0b111, 0b101, 0b101],
+    [0b110, 0b101, 0b110, 0b101, 0b110],
+    [0b111, 0b100, 0b100, 0b100, 0b111],
+    [0b110, 0b101, 0b101, 0b101, 0b110],
+    [0b111, 0b100, 0b111, 0b100, 0b111],
+    [0b111, 0b100, 0b111, 0b100, 0b100],
+];
+
+/// Hex digits 0 to F with 2x3 pixels for the smallest raster heights. Each row is a bitmask
+/// where bit 1 is the left pixel. The digits only hint at the shapes of [`BIG_DIGITS`], but they
+/// are all different.
+const SMALL_DIGITS: [[u8; 3]; 16] = [
+    [0b11, 0b11, 0b11],
+    [0b01, 0b01, 0b01],
+    [0b11, 0b01, 0b10],
+    [0b11, 0b01, 0b11],
+    [0b10, 0b11, 0b01],
+    [0b11, 0b10, 0b01],
+    [0b10, 0b11, 0b11],
+    [0b11, 0b01, 0b01],
+    [0b11, 0b00, 0b11],
+    [0b11, 0b11, 0b01],
+    [0b11, 0b11, 0b10],
+    [0b10, 0b11, 0b10],
+    [0b11, 0b10, 0b11],
+    [0b01, 0b11, 0b11],
+    [0b10, 0b10, 0b11],
+    [0b11, 0b10, 0b10],
+];
+
+/// Intensity of the box. The digits are cut out of it.
+const INTENSITY: u8 = 0xff;
+
+/// Font of the hex digits.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum DigitFont {
+    /// [`BIG_DIGITS`]
+    Big,
+    /// [`SMALL_DIGITS`]
+    Small,
+}
+
+impl DigitFont {
+    /// Width and height of a digit in pixels, before scaling.
+    const fn size(self) -> (usize, usize) {
+        match self {
+            Self::Big => (3, 5),
+            Self::Small => (2, 3),
+        }
+    }
+
+    /// Returns true if the pixel of the digit is set.
+    const fn is_set(self, digit: usize, x: usize, y: usize) -> bool {
+        let (width, _) = self.size();
+        let row = match self {
+            Self::Big => BIG_DIGITS[digit][y],
+            Self::Small => SMALL_DIGITS[digit][y],
+        };
+        row & (1 << (width - 1 - x)) != 0
+    }
+}
+
+/// Layouts of the box in the order of preference: the font of the digits, the number of cells,
+/// and the margin between the digits and the edge of the box.
+const LAYOUTS: [(DigitFont, usize, usize); 8] = [
+    (DigitFont::Big, 1, 1),
+    (DigitFont::Big, 1, 0),
+    (DigitFont::Big, 2, 1),
+    (DigitFont::Big, 2, 0),
+    (DigitFont::Small, 1, 1),
+    (DigitFont::Small, 1, 0),
+    (DigitFont::Small, 2, 1),
+    (DigitFont::Small, 2, 0),
+];
+
+/// A glyph for a char that shows its code point in a box. See the
+/// [module description](self).
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct HexBox {
+    code_point: u32,
+    /// Number of cells that the box occupies.
+    cells: usize,
+    width: usize,
+    height: usize,
+    font: DigitFont,
+    /// Grid of hex digits: 2x2 for chars of the Basic Multilingual Plane, otherwise 2x3 or 3x2.
+    columns: usize,
+    rows: usize,
+    /// Size of a pixel of the digit font. 0, if the digits don't fit.
+    scale: usize,
+    /// Space between the digits and the edge of the box, 1 or 0.
+    margin: usize,
+    /// Left and top edge of the box in the raster.
+    x: usize,
+    y: usize,
+    /// Size of the box.
+    box_width: usize,
+    box_height: usize,
+}
+
+impl HexBox {
+    /// Creates the hex box for a char in a raster with cells of the given size. The box
+    /// occupies one or two cells, see [`Self::cell_width`].
+    pub const fn new(c: char, cell_width: usize, height: usize) -> Self {
+        let code_point = c as u32;
+        let mut layout = 0;
+        while layout < LAYOUTS.len() {
+            let (font, cells, margin) = LAYOUTS[layout];
+            let width = cell_width * cells;
+            // chars of the Basic Multilingual Plane have four digits, all others six
+            let (mut columns, mut rows) = if code_point > 0xffff { (2, 3) } else { (2, 2) };
+            let mut scale = Self::max_scale(width, height, font, columns, rows, margin);
+            if scale == 0 && code_point > 0xffff {
+                columns = 3;
+                rows = 2;
+                scale = Self::max_scale(width, height, font, columns, rows, margin);
+            }
+            if scale > 0 {
+                let (box_width, box_height) = Self::box_size(font, columns, rows, scale, margin);
+                return Self {
+                    code_point,
+                    cells,
+                    width,
+                    height,
+                    font,
+                    columns,
+                    rows,
+                    scale,
+                    margin,
+                    x: (width - box_width) / 2,
+                    y: (height - box_height) / 2,
+                    box_width,
+                    box_height,
+                };
+            }
+            layout += 1;
+        }
+        // only the frame, as there is no space for the digits
+        Self {
+            code_point,
+            cells: 1,
+            width: cell_width,
+            height,
+            font: DigitFont::Big,
+            columns: 0,
+            rows: 0,
+            scale: 0,
+            margin: 0,
+            x: 0,
+            y: 0,
+            box_width: cell_width,
+            box_height: height,
+        }
+    }
+
+    /// Creates the hex box for a char with the raster size of the given [`FontWeight`] and
+    /// [`RasterHeight`], i.e., as the replacement for [`crate::get_raster`].
+    pub const fn for_raster(c: char, style: FontWeight, size: RasterHeight) -> Self {
+        Self::new(c, get_raster_width(style, size), size.val())
+    }
+
+    /// The code point that the box shows.
+    #[inline]
+    pub const fn code_point(&self) -> u32 {
+        self.code_point
+    }
+
+    /// Number of cells that the box occupies, 1 or 2, like
+    /// [`crate::RasterizedChar::cell_width`].
+    #[inline]
+    pub const fn cell_width(&self) -> usize {
+        self.cells
+    }
+
+    /// Width of the raster, i.e., of all cells that the box occupies.
+    #[inline]
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Height of the raster.
+    #[inline]
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the intensity of a pixel from 0 to 255, like [`crate::RasterizedChar::raster`].
+    /// Pixels outside of the raster are 0.
+    pub const fn pixel(&self, x: usize, y: usize) -> u8 {
+        if x < self.x || y < self.y || x >= self.x + self.box_width || y >= self.y + self.box_height
+        {
+            return 0;
+        }
+        let (x, y) = (x - self.x, y - self.y);
+        if self.scale == 0 {
+            let frame = x == 0 || y == 0 || x == self.box_width - 1 || y == self.box_height - 1;
+            return if frame { INTENSITY } else { 0 };
+        }
+        // position in the grid of digits
+        if x < self.margin || y < self.margin {
+            return INTENSITY;
+        }
+        let (digit_width, digit_height) = self.font.size();
+        let (x, y) = (
+            (x - self.margin) / self.scale,
+            (y - self.margin) / self.scale,
+        );
+        let (column, digit_x) = (x / (digit_width + 1), x % (digit_width + 1));
+        let (row, digit_y) = (y / (digit_height + 1), y % (digit_height + 1));
+        if column >= self.columns
+            || row >= self.rows
+            || digit_x >= digit_width
+            || digit_y >= digit_height
+        {
+            return INTENSITY;
+        }
+        let index = (self.rows - 1 - row) * self.columns + (self.columns - 1 - column);
+        let digit = (self.code_point >> (index * 4)) & 0xf;
+        if self.font.is_set(digit as usize, digit_x, digit_y) {
+            0
+        } else {
+            INTENSITY
+        }
+    }
+
+    /// Returns the biggest scale of the digits so that the box fits into the raster, or 0.
+    const fn max_scale(
+        width: usize,
+        height: usize,
+        font: DigitFont,
+        columns: usize,
+        rows: usize,
+        margin: usize,
+    ) -> usize {
+        let mut scale = height;
+        while scale > 0 {
+            let (box_width, box_height) = Self::box_size(font, columns, rows, scale, margin);
+            if box_width <= width && box_height <= height {
+                break;
+            }
+            scale -= 1;
+        }
+        scale
+    }
+
+    /// Size of the box for the given grid of digits and scale. There is one pixel of space
+    /// between two digits and `margin` pixels to the edge of the box.
+    const fn box_size(
+        font: DigitFont,
+        columns: usize,
+        rows: usize,
+        scale: usize,
+        margin: usize,
+    ) -> (usize, usize) {
+        let (digit_width, digit_height) = font.size();
+        let digits_width = (columns * (digit_width + 1) - 1) * scale;
+        let digits_height = (rows * (digit_height + 1) - 1) * scale;
+        (digits_width + 2 * margin, digits_height + 2 * margin)
+    }
+}
+
+/// Source of [`HexBox`]es for all chars, in cells of the given size. Put it at the end of a
+/// chain of sources, so that chars that no other source knows show their code point.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct HexBoxSource {
+    cell_width: usize,
+    height: usize,
+}
+
+impl HexBoxSource {
+    /// Creates the source for cells of the given size, see [`HexBox::new`].
+    pub const fn new(cell_width: usize, height: usize) -> Self {
+        Self { cell_width, height }
+    }
+
+    /// Creates the source for the raster size of the given [`FontWeight`] and
+    /// [`RasterHeight`], see [`HexBox::for_raster`].
+    pub const fn for_raster(style: FontWeight, size: RasterHeight) -> Self {
+        Self::new(get_raster_width(style, size), size.val())
+    }
+}
+
+impl GlyphSource for HexBoxSource {
+    fn glyph(&self, c: char) -> Option<Glyph<'_>> {
+        Some(Glyph::from(HexBox::new(c, self.cell_width, self.height)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Reads the digits of a box with a big font and a margin from the pixels, in the order of
+    /// the grid.
+    fn read_digits(hex_box: &HexBox) -> [Option<usize>; 6] {
+        let mut digits = [None; 6];
+        let (digit_width, digit_height) = hex_box.font.size();
+        for row in 0..hex_box.rows {
+            for column in 0..hex_box.columns {
+                let left = hex_box.x + hex_box.margin + column * (digit_width + 1) * hex_box.scale;
+                let top = hex_box.y + hex_box.margin + row * (digit_height + 1) * hex_box.scale;
+                // a digit is cut out of the box
+                let is_set = |x: usize, y: usize| {
+                    hex_box.pixel(left + x * hex_box.scale, top + y * hex_box.scale) == 0
+                };
+                digits[row * hex_box.columns + column] = (0..16).find(|&digit| {
+                    (0..digit_height).all(|y| {
+                        (0..digit_width).all(|x| hex_box.font.is_set(digit, x, y) == is_set(x, y))
+                    })
+                });
+            }
+        }
+        digits
+    }
+
+    #[test]
+    fn test_digit_order() {
+        let hex_box = HexBox::new('\u{1234}', 9, 16);
+        assert_eq!(hex_box.cell_width(), 1);
+        assert_eq!(
+            read_digits(&hex_box),
+            [Some(1), Some(2), Some(3), Some(4), None, None]
+        );
+
+        let hex_box = HexBox::new('\u{10fedc}', 30, 64);
+        assert_eq!((hex_box.columns, hex_box.rows), (2, 3));
+        assert_eq!(
+            read_digits(&hex_box),
+            [Some(1), Some(0), Some(0xf), Some(0xe), Some(0xd), Some(0xc)]
+        );
+
+        let hex_box = HexBox::new('\u{1f600}', 9, 16);
+        assert_eq!((hex_box.columns, hex_box.rows), (3, 2));
+        assert_eq!(
+            read_digits(&hex_box),
+            [Some(0), Some(1), Some(0xf), Some(6), Some(0), Some(0)]
+        );
+    }
+
+    #[test]
+    fn test_digits_are_distinct() {
+        for (i, a) in BIG_DIGITS.iter().enumerate() {
+            assert!(BIG_DIGITS[i + 1..].iter().all(|b| a != b));
+        }
+        for (i, a) in SMALL_DIGITS.iter().enumerate() {
+            assert!(SMALL_DIGITS[i + 1..].iter().all(|b| a != b));
+        }
+    }
+
+    #[test]
+    fn test_scaling() {
+        // 2x2 digits of 3x5 pixels with one pixel of space and margin: 9x13 pixels
+        let hex_box = HexBox::new('A', 9, 13);
+        assert_eq!(hex_box.scale, 1);
+        assert_eq!((hex_box.box_width, hex_box.box_height), (9, 13));
+        let hex_box = HexBox::new('A', 27, 64);
+        assert_eq!(hex_box.scale, 3);
+        assert_eq!((hex_box.box_width, hex_box.box_height), (23, 35));
+        // centered
+        assert_eq!((hex_box.x, hex_box.y), (2, 14));
+        assert_eq!(hex_box.pixel(1, 20), 0);
+        assert_eq!(hex_box.pixel(2, 20), INTENSITY);
+        // each pixel of the digit font is a 3x3 square: the top row of '0' is set, followed by
+        // the space to the next digit
+        for (x, y) in [(3, 15), (11, 15), (3, 17), (11, 17)] {
+            assert_eq!(hex_box.pixel(x, y), 0);
+        }
+        assert_eq!(hex_box.pixel(12, 15), INTENSITY);
+        assert_eq!(hex_box.pixel(14, 17), INTENSITY);
+        assert_eq!(
+            read_digits(&hex_box)[..4],
+            [Some(0), Some(0), Some(4), Some(1)]
+        );
+    }
+
+    #[test]
+    fn test_small_rasters() {
+        // regular raster widths of the raster heights 8, 10, and 16
+        for (cell_width, height) in [(4, 8), (5, 10), (9, 16)] {
+            for c in ['A', '\u{ffff}', '\u{1f600}', '\u{10ffff}'] {
+                let hex_box = HexBox::new(c, cell_width, height);
+                assert!(hex_box.scale > 0, "{:?} in {}x{}", c, cell_width, height);
+                assert_eq!(hex_box.width(), hex_box.cell_width() * cell_width);
+                assert!(hex_box.box_width <= hex_box.width());
+                assert!(hex_box.box_height <= height);
+            }
+        }
+        // the default raster height shows emojis with the big font in two cells
+        let hex_box = HexBox::new('\u{1f600}', 9, 16);
+        assert_eq!(hex_box.cell_width(), 2);
+        assert_eq!(hex_box.font, DigitFont::Big);
+        // the smallest raster height needs the small font without margin
+        let hex_box = HexBox::new('A', 4, 8);
+        assert_eq!(hex_box.font, DigitFont::Small);
+        assert_eq!((hex_box.cell_width(), hex_box.margin), (2, 0));
+        assert_eq!((hex_box.box_width, hex_box.box_height), (5, 7));
+    }
+
+    #[test]
+    fn test_frame_only() {
+        let hex_box = HexBox::new('A', 4, 4);
+        assert_eq!(hex_box.scale, 0);
+        assert_eq!((hex_box.width(), hex_box.height()), (4, 4));
+        for y in 0..4 {
+            for x in 0..4 {
+                let frame = x == 0 || y == 0 || x == 3 || y == 3;
+                assert_eq!(hex_box.pixel(x, y), if frame { INTENSITY } else { 0 });
+            }
+        }
+        assert_eq!(hex_box.pixel(4, 0), 0);
+    }
+
+    #[test]
+    fn test_hex_box_source() {
+        let source = HexBoxSource::new(9, 16);
+        let glyph = source.glyph('\u{1f600}').unwrap();
+        let hex_box = HexBox::new('\u{1f600}', 9, 16);
+        assert_eq!((glyph.width(), glyph.height()), (18, 16));
+        for y in 0..16 {
+            for x in 0..18 {
+                assert_eq!(glyph.pixel(x, y), hex_box.pixel(x, y));
+            }
+        }
+    }
+}
+
+// # THIS FILE GETS AUTO GENERATED BY THE PROJECT IN "../codegen" (see repository!)
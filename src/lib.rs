@@ -14,7 +14,7 @@
 //! * ✅ different sizes and font weights (light, normal, bold)
 //! * ✅ nice anti-aliasing/smoothing and better looking than legacy bitmap fonts
 //! * ✅ every pixel is encoded in a byte (0-255) and not a bit, which results in a much nicer result on the screen.
-//! * ✅ relevant font sizes, such as 14, 18, 22, and 32px (as optional build time features)
+//! * ✅ relevant raster heights: 8, 10, 12, 14, 16, 20, 24, 32, 40, 48, and 64px (as optional build time features)
 //! * ✅ zero dependencies
 //! * ✅ All characters are aligned in their box/raster. If they are printed next to each other, the result looks nice.
 //!
@@ -57,19 +57,26 @@
 //! ```
 //!
 //! ## Cargo Features and Crate Size
-//! By default, only a reasonable subset of possible features is included. The raw crate-size is a few
-//! MiB in size but after compilation and discarding irrelevant parts (i.e., size 14, regular font,
-//! only ASCII), the overhead should be at less than 100-200 KiB in binary size, according to my
-//! measurements. The compiler can reliably discard unused sizes or weights, but not so for unicode
-//! ranges. Thus, it is recommended to include no more features than necessary.
+//! By default, only a reasonable subset of possible features is included. The rasters of each
+//! unicode range are packed into a single binary file. The crate is about 5 MiB to download and
+//! about 45 MiB unpacked, most of it for the big raster heights. After compilation and discarding
+//! irrelevant parts (i.e., size 14, regular font, only ASCII), the overhead should be at less than
+//! 100-200 KiB in binary size, according to my measurements. The compiler can reliably discard
+//! unused sizes or weights, but not so for unicode ranges. Thus, it is recommended to include no
+//! more features than necessary.
 //!
 //! With all features included inside the binary, and without any discarding by the compiler, you
-//! can expect 5 or more MiB of memory requirements. However, this would require the rather unlikely
+//! can expect about 45 MiB of memory requirements. However, this would require the rather unlikely
 //! case that you use different sizes and font weights simultaneously.
 //!
+//! The big raster heights 40, 48, and 64 (`size_40`, `size_48`, `size_64`) are meant for boot
+//! splash or recovery screens. With all unicode ranges, each of them needs 2 to 5 MiB per font
+//! weight in the final binary.
+//!
 //! Not all unicode ranges include all symbols. Control characters are not there as well as
-//! protected spaces and similar characters. For a full support of all unicode ranges, use an
-//! on-the-fly rasterization process instead of this crate.
+//! protected spaces and similar characters. With the `unicode-control-pictures` feature, the
+//! `control_pictures` module can show them as '␀', '␛', and so on. For a full support of all
+//! unicode ranges, use an on-the-fly rasterization process instead of this crate.
 
 // # THIS FILE GETS AUTO GENERATED BY THE PROJECT IN "../codegen" (see repository!)
 
@@ -93,8 +100,26 @@
 
 // # THIS FILE GETS AUTO GENERATED BY THE PROJECT IN "../codegen" (see repository!)
 
+use core::convert::TryFrom;
+use core::fmt;
+use core::ops::Index;
+use core::slice::ChunksExact;
+
+#[cfg(feature = "bold")]
 mod bold;
+#[cfg(feature = "unicode-braille")]
+pub mod braille;
+#[cfg(feature = "unicode-combining-diacritical-marks")]
+pub mod combining;
+#[cfg(feature = "unicode-control-pictures")]
+pub mod control_pictures;
+pub mod glyph_source;
+pub mod hex_box;
+#[cfg(feature = "light")]
 mod light;
+pub mod psf;
+mod raster_table;
+#[cfg(feature = "regular")]
 mod regular;
 
 /// Describes the relevant information for a rendered char of the font.
@@ -102,20 +127,23 @@ mod regular;
 pub struct RasterizedChar {
     /// The actual font data that is `height` * `width` bytes in size.
     /// Each byte describes the intensity of a pixel from 0 to 255.
-    raster: &'static [&'static [u8]],
+    raster: Raster,
     /// Height of the raster box. The actual font size is slightly smaller.
     height: usize,
     /// The width of the rasterized char. It is guaranteed, that all chars
     /// of the same font weight and raster height also have the same width
-    /// (as you would expect from a mono font.)
+    /// (as you would expect from a mono font), except for wide chars, which
+    /// are twice as wide.
     width: usize,
+    /// Number of cells that the char occupies: 1 or 2.
+    cell_width: usize,
 }
 
 impl RasterizedChar {
     /// The actual font data that is `height` * `width` bytes in size.
     /// Each byte describes the intensity of a pixel from 0 to 255.
     #[inline]
-    pub const fn raster(&self) -> &'static [&'static [u8]] {
+    pub const fn raster(&self) -> Raster {
         self.raster
     }
 
@@ -127,44 +155,174 @@ impl RasterizedChar {
 
     /// The width of the raster char. It is guaranteed, that all chars
     /// of the same font weight and raster height also have the same width
-    /// (as you would expect from a mono font).
+    /// (as you would expect from a mono font), which is [`get_raster_width`].
+    /// Wide chars are twice as wide, see [`Self::cell_width`].
     #[inline]
     pub const fn width(&self) -> usize {
         self.width
     }
+
+    /// Number of cells that the char occupies: 1 for almost all chars, 2 for wide chars, such
+    /// as '�' or '⅓', whose glyphs are much wider than a regular cell. A text layout should
+    /// advance by this number of cells. Which chars are wide is the same for all font weights
+    /// and raster heights.
+    ///
+    /// ```rust
+    /// use noto_sans_mono_bitmap::{get_raster, get_raster_width, FontWeight, RasterHeight};
+    ///
+    /// let (style, size) = (FontWeight::Regular, RasterHeight::Size16);
+    /// let a = get_raster('A', style, size).unwrap();
+    /// assert_eq!(a.cell_width(), 1);
+    /// assert_eq!(a.width(), get_raster_width(style, size));
+    /// ```
+    #[inline]
+    pub const fn cell_width(&self) -> usize {
+        self.cell_width
+    }
+}
+
+/// The pixels of a [`RasterizedChar`], row by row.
+///
+/// Each row is a slice of [`RasterizedChar::width`] bytes, and each byte describes the
+/// intensity of a pixel from 0 to 255. The rows can be iterated and indexed like a slice of
+/// rows.
+///
+/// ```rust
+/// use noto_sans_mono_bitmap::{get_raster, FontWeight, RasterHeight};
+///
+/// let a = get_raster('A', FontWeight::Regular, RasterHeight::Size16).unwrap();
+/// let raster = a.raster();
+/// assert_eq!(raster.len(), a.height());
+/// assert_eq!(raster[0].len(), a.width());
+/// assert!(raster.iter().any(|row| row.iter().any(|&pixel| pixel == 255)));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Raster {
+    /// All pixels, row by row.
+    pixels: &'static [u8],
+    /// Number of pixels in each row.
+    width: usize,
+}
+
+impl Raster {
+    /// All pixels of the raster, row by row, without any padding.
+    #[inline]
+    pub const fn pixels(&self) -> &'static [u8] {
+        self.pixels
+    }
+
+    /// Number of rows, i.e., the height of the raster.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.pixels.len() / self.width
+    }
+
+    /// Whether the raster has no rows. This is never the case for the rasters of this crate.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.pixels.is_empty()
+    }
+
+    /// Returns the row with the given index, or `None`, if it is outside of the raster.
+    #[inline]
+    pub fn get(&self, y: usize) -> Option<&'static [u8]> {
+        self.iter().nth(y)
+    }
+
+    /// Returns an iterator over the rows, from the top to the bottom.
+    #[inline]
+    pub fn iter(&self) -> ChunksExact<'static, u8> {
+        self.pixels.chunks_exact(self.width)
+    }
+}
+
+impl Index<usize> for Raster {
+    type Output = [u8];
+
+    /// Returns the row with the given index. Panics, if it is outside of the raster.
+    fn index(&self, y: usize) -> &Self::Output {
+        self.get(y).expect("row is outside of the raster")
+    }
+}
+
+impl IntoIterator for Raster {
+    type Item = &'static [u8];
+    type IntoIter = ChunksExact<'static, u8>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
 }
 
 /// Supported font weights.
 ///
 /// The available variants depend on the selected Cargo build features.
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 #[repr(usize)]
 pub enum FontWeight {
     #[cfg(feature = "light")]
-    Light,
+    Light = 0,
     #[cfg(feature = "regular")]
-    Regular,
+    Regular = 1,
     #[cfg(feature = "bold")]
-    Bold,
+    Bold = 2,
 }
 
 impl FontWeight {
-    /// Returns the numeric value of the enum variant.
+    /// All font weights that are available with the selected Cargo build features,
+    /// ordered from the lightest to the boldest weight.
+    pub const ALL: &'static [Self] = &[
+        #[cfg(feature = "light")]
+        Self::Light,
+        #[cfg(feature = "regular")]
+        Self::Regular,
+        #[cfg(feature = "bold")]
+        Self::Bold,
+    ];
+
+    /// Returns the numeric value of the enum variant. The value doesn't depend on the
+    /// selected Cargo build features.
     #[inline]
     pub const fn val(self) -> usize {
         self as _
     }
 }
 
-/// The height of the pre-rasterized font. The font size will be a a few
-/// percent less, because each letter contains vertical padding for proper
-/// alignment of chars (i.e. ÄyA). The width of each character will be also
-/// less than the height, because there is no horizontal padding included.
+impl TryFrom<usize> for FontWeight {
+    type Error = UnsupportedValueError;
+
+    /// Converts a value previously obtained from [`FontWeight::val`] back into the variant.
+    fn try_from(value: usize) -> Result<Self, Self::Error> {
+        match value {
+            #[cfg(feature = "light")]
+            0 => Ok(Self::Light),
+            #[cfg(feature = "regular")]
+            1 => Ok(Self::Regular),
+            #[cfg(feature = "bold")]
+            2 => Ok(Self::Bold),
+            _ => Err(UnsupportedValueError(value)),
+        }
+    }
+}
+
+/// The height of the pre-rasterized font.
+///
+/// The font size will be a a few percent less, because each letter contains vertical padding
+/// for proper alignment of chars (i.e. ÄyA). The width of each character will be also less
+/// than the height, because there is no horizontal padding included.
 ///
 /// The available variants depend on the selected Cargo build features.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(usize)]
 pub enum RasterHeight {
+    #[cfg(feature = "size_8")]
+    Size8 = 8,
+    #[cfg(feature = "size_10")]
+    Size10 = 10,
+    #[cfg(feature = "size_12")]
+    Size12 = 12,
+    #[cfg(feature = "size_14")]
+    Size14 = 14,
     #[cfg(feature = "size_16")]
     Size16 = 16,
     #[cfg(feature = "size_20")]
@@ -173,9 +331,42 @@ pub enum RasterHeight {
     Size24 = 24,
     #[cfg(feature = "size_32")]
     Size32 = 32,
+    #[cfg(feature = "size_40")]
+    Size40 = 40,
+    #[cfg(feature = "size_48")]
+    Size48 = 48,
+    #[cfg(feature = "size_64")]
+    Size64 = 64,
 }
 
 impl RasterHeight {
+    /// All raster heights that are available with the selected Cargo build features,
+    /// ordered from the smallest to the biggest height.
+    pub const ALL: &'static [Self] = &[
+        #[cfg(feature = "size_8")]
+        Self::Size8,
+        #[cfg(feature = "size_10")]
+        Self::Size10,
+        #[cfg(feature = "size_12")]
+        Self::Size12,
+        #[cfg(feature = "size_14")]
+        Self::Size14,
+        #[cfg(feature = "size_16")]
+        Self::Size16,
+        #[cfg(feature = "size_20")]
+        Self::Size20,
+        #[cfg(feature = "size_24")]
+        Self::Size24,
+        #[cfg(feature = "size_32")]
+        Self::Size32,
+        #[cfg(feature = "size_40")]
+        Self::Size40,
+        #[cfg(feature = "size_48")]
+        Self::Size48,
+        #[cfg(feature = "size_64")]
+        Self::Size64,
+    ];
+
     /// Returns the numeric value of the variant.
     #[inline]
     pub const fn val(self) -> usize {
@@ -183,15 +374,186 @@ impl RasterHeight {
     }
 }
 
+impl TryFrom<usize> for RasterHeight {
+    type Error = UnsupportedValueError;
+
+    /// Converts a height in pixels into the variant, if it was selected by the Cargo build
+    /// features. Use [`closest_raster_height`] for heights that don't match exactly.
+    fn try_from(value: usize) -> Result<Self, Self::Error> {
+        match value {
+            #[cfg(feature = "size_8")]
+            8 => Ok(Self::Size8),
+            #[cfg(feature = "size_10")]
+            10 => Ok(Self::Size10),
+            #[cfg(feature = "size_12")]
+            12 => Ok(Self::Size12),
+            #[cfg(feature = "size_14")]
+            14 => Ok(Self::Size14),
+            #[cfg(feature = "size_16")]
+            16 => Ok(Self::Size16),
+            #[cfg(feature = "size_20")]
+            20 => Ok(Self::Size20),
+            #[cfg(feature = "size_24")]
+            24 => Ok(Self::Size24),
+            #[cfg(feature = "size_32")]
+            32 => Ok(Self::Size32),
+            #[cfg(feature = "size_40")]
+            40 => Ok(Self::Size40),
+            #[cfg(feature = "size_48")]
+            48 => Ok(Self::Size48),
+            #[cfg(feature = "size_64")]
+            64 => Ok(Self::Size64),
+            _ => Err(UnsupportedValueError(value)),
+        }
+    }
+}
+
+/// Error of the `TryFrom<usize>` implementations of [`FontWeight`] and [`RasterHeight`].
+/// Contains the value that has no corresponding variant with the selected Cargo build
+/// features.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct UnsupportedValueError(pub usize);
+
+impl fmt::Display for UnsupportedValueError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "the value {} has no variant with the selected Cargo features",
+            self.0
+        )
+    }
+}
+
+/// Returns the available [`RasterHeight`] that is the closest to `target_px`.
+///
+/// If two heights are equally close, the smaller one is returned. Returns `None` only if no
+/// raster height was selected by the Cargo build features.
+///
+/// ```rust
+/// use noto_sans_mono_bitmap::{closest_raster_height, RasterHeight};
+///
+/// assert_eq!(closest_raster_height(17), Some(RasterHeight::Size16));
+/// ```
+pub const fn closest_raster_height(target_px: usize) -> Option<RasterHeight> {
+    let mut closest: Option<RasterHeight> = None;
+    let mut closest_diff = usize::MAX;
+    let mut i = 0;
+    while i < RasterHeight::ALL.len() {
+        let height = RasterHeight::ALL[i];
+        let diff = if height.val() > target_px {
+            height.val() - target_px
+        } else {
+            target_px - height.val()
+        };
+        // strict comparison: on equal distance, the smaller height wins
+        if diff < closest_diff {
+            closest = Some(height);
+            closest_diff = diff;
+        }
+        i += 1;
+    }
+    closest
+}
+
+/// Returns the biggest available [`RasterHeight`] for a text grid on a framebuffer.
+///
+/// The grid contains at least `columns` x `rows` characters of the given [`FontWeight`].
+/// Returns `None` if not even the smallest available raster height can fulfill this.
+///
+/// How many chars fit only depends on the resolution of the framebuffer, hence, this takes no
+/// DPI. The physical size of the display only matters for how big the chars appear. To get
+/// both, a grid and a physical text size, take the smaller result of this function and of
+/// [`raster_height_for_dpi`], as in the second example.
+///
+/// ```rust
+/// use noto_sans_mono_bitmap::{get_raster_width, raster_height_for_grid, FontWeight};
+///
+/// // a classic 80x25 text console on a 1024x768 framebuffer
+/// let height = raster_height_for_grid(FontWeight::Regular, 1024, 768, 80, 25).unwrap();
+/// assert!(get_raster_width(FontWeight::Regular, height) * 80 <= 1024);
+/// assert!(height.val() * 25 <= 768);
+/// ```
+///
+/// ```rust
+/// use noto_sans_mono_bitmap::{raster_height_for_dpi, raster_height_for_grid, FontWeight};
+///
+/// // 12pt text, but at least 80x25 chars, on a 1024x768 framebuffer with 96 DPI
+/// let for_grid = raster_height_for_grid(FontWeight::Regular, 1024, 768, 80, 25).unwrap();
+/// let for_dpi = raster_height_for_dpi(96, 12).unwrap();
+/// let height = if for_dpi.val() < for_grid.val() { for_dpi } else { for_grid };
+/// assert!(height.val() * 25 <= 768);
+/// ```
+pub const fn raster_height_for_grid(
+    style: FontWeight,
+    framebuffer_width: usize,
+    framebuffer_height: usize,
+    columns: usize,
+    rows: usize,
+) -> Option<RasterHeight> {
+    let mut best: Option<RasterHeight> = None;
+    let mut i = 0;
+    // ALL is sorted in ascending order; hence, the last match is the biggest one
+    while i < RasterHeight::ALL.len() {
+        let height = RasterHeight::ALL[i];
+        // an overflow means that the grid doesn't fit
+        let fits_horizontally = match get_raster_width(style, height).checked_mul(columns) {
+            Some(width) => width <= framebuffer_width,
+            None => false,
+        };
+        let fits_vertically = match height.val().checked_mul(rows) {
+            Some(height) => height <= framebuffer_height,
+            None => false,
+        };
+        if fits_horizontally && fits_vertically {
+            best = Some(height);
+        }
+        i += 1;
+    }
+    best
+}
+
+/// Returns the available [`RasterHeight`] that comes closest to a physical height.
+///
+/// The physical height is `height_pt` typographic points (1/72 inch) on a display with the
+/// given DPI (dots per inch). The DPI can be calculated from the framebuffer resolution and
+/// the physical display size, e.g., from the EDID: `dpi = height_px * 254 / (height_mm * 10)`.
+///
+/// The height refers to the whole raster and not only to the font size, which is slightly
+/// smaller. Returns `None` if no raster height was selected by the Cargo build features, or if
+/// the height in pixels overflows.
+///
+/// ```rust
+/// use noto_sans_mono_bitmap::{raster_height_for_dpi, RasterHeight};
+///
+/// // 12pt on a display with 96 DPI are 16 pixels
+/// assert_eq!(raster_height_for_dpi(96, 12), Some(RasterHeight::Size16));
+/// assert_eq!(raster_height_for_dpi(usize::MAX, 12), None);
+/// ```
+pub const fn raster_height_for_dpi(dpi: usize, height_pt: usize) -> Option<RasterHeight> {
+    match height_pt.checked_mul(dpi) {
+        Some(dots) => closest_raster_height(dots / 72),
+        None => None,
+    }
+}
+
 /// Returns a [`RasterizedChar`] for the given char, [`FontWeight`], and [`RasterHeight`].
 ///
 /// Returns None, if the given char is not known by the font. In this case,
-/// you could fall back to `get_raster(' ', ...)`.
+/// you could fall back to `get_raster(' ', ...)` or to a [`hex_box::HexBox`], which shows the
+/// code point of the char.
 #[inline]
 pub fn get_raster(c: char, style: FontWeight, size: RasterHeight) -> Option<RasterizedChar> {
-    let raster = match style {
+    let pixels = match style {
         #[cfg(feature = "light")]
         FontWeight::Light => match size {
+            #[cfg(feature = "size_8")]
+            RasterHeight::Size8 => crate::light::size_8::get_char(c),
+            #[cfg(feature = "size_10")]
+            RasterHeight::Size10 => crate::light::size_10::get_char(c),
+            #[cfg(feature = "size_12")]
+            RasterHeight::Size12 => crate::light::size_12::get_char(c),
+            #[cfg(feature = "size_14")]
+            RasterHeight::Size14 => crate::light::size_14::get_char(c),
             #[cfg(feature = "size_16")]
             RasterHeight::Size16 => crate::light::size_16::get_char(c),
             #[cfg(feature = "size_20")]
@@ -200,9 +562,23 @@ pub fn get_raster(c: char, style: FontWeight, size: RasterHeight) -> Option<Rast
             RasterHeight::Size24 => crate::light::size_24::get_char(c),
             #[cfg(feature = "size_32")]
             RasterHeight::Size32 => crate::light::size_32::get_char(c),
+            #[cfg(feature = "size_40")]
+            RasterHeight::Size40 => crate::light::size_40::get_char(c),
+            #[cfg(feature = "size_48")]
+            RasterHeight::Size48 => crate::light::size_48::get_char(c),
+            #[cfg(feature = "size_64")]
+            RasterHeight::Size64 => crate::light::size_64::get_char(c),
         },
         #[cfg(feature = "regular")]
         FontWeight::Regular => match size {
+            #[cfg(feature = "size_8")]
+            RasterHeight::Size8 => crate::regular::size_8::get_char(c),
+            #[cfg(feature = "size_10")]
+            RasterHeight::Size10 => crate::regular::size_10::get_char(c),
+            #[cfg(feature = "size_12")]
+            RasterHeight::Size12 => crate::regular::size_12::get_char(c),
+            #[cfg(feature = "size_14")]
+            RasterHeight::Size14 => crate::regular::size_14::get_char(c),
             #[cfg(feature = "size_16")]
             RasterHeight::Size16 => crate::regular::size_16::get_char(c),
             #[cfg(feature = "size_20")]
@@ -211,9 +587,23 @@ pub fn get_raster(c: char, style: FontWeight, size: RasterHeight) -> Option<Rast
             RasterHeight::Size24 => crate::regular::size_24::get_char(c),
             #[cfg(feature = "size_32")]
             RasterHeight::Size32 => crate::regular::size_32::get_char(c),
+            #[cfg(feature = "size_40")]
+            RasterHeight::Size40 => crate::regular::size_40::get_char(c),
+            #[cfg(feature = "size_48")]
+            RasterHeight::Size48 => crate::regular::size_48::get_char(c),
+            #[cfg(feature = "size_64")]
+            RasterHeight::Size64 => crate::regular::size_64::get_char(c),
         },
         #[cfg(feature = "bold")]
         FontWeight::Bold => match size {
+            #[cfg(feature = "size_8")]
+            RasterHeight::Size8 => crate::bold::size_8::get_char(c),
+            #[cfg(feature = "size_10")]
+            RasterHeight::Size10 => crate::bold::size_10::get_char(c),
+            #[cfg(feature = "size_12")]
+            RasterHeight::Size12 => crate::bold::size_12::get_char(c),
+            #[cfg(feature = "size_14")]
+            RasterHeight::Size14 => crate::bold::size_14::get_char(c),
             #[cfg(feature = "size_16")]
             RasterHeight::Size16 => crate::bold::size_16::get_char(c),
             #[cfg(feature = "size_20")]
@@ -222,24 +612,49 @@ pub fn get_raster(c: char, style: FontWeight, size: RasterHeight) -> Option<Rast
             RasterHeight::Size24 => crate::bold::size_24::get_char(c),
             #[cfg(feature = "size_32")]
             RasterHeight::Size32 => crate::bold::size_32::get_char(c),
+            #[cfg(feature = "size_40")]
+            RasterHeight::Size40 => crate::bold::size_40::get_char(c),
+            #[cfg(feature = "size_48")]
+            RasterHeight::Size48 => crate::bold::size_48::get_char(c),
+            #[cfg(feature = "size_64")]
+            RasterHeight::Size64 => crate::bold::size_64::get_char(c),
         },
     };
 
-    raster.map(|raster| RasterizedChar {
-        raster,
-        height: size.val(),
-        width: get_raster_width(style, size),
+    pixels.map(|pixels| {
+        let width = get_raster_width(style, size);
+        // wide chars have twice as many pixels
+        let cell_width = pixels.len() / (width * size.val());
+        RasterizedChar {
+            raster: Raster {
+                pixels,
+                width: width * cell_width,
+            },
+            height: size.val(),
+            width: width * cell_width,
+            cell_width,
+        }
     })
 }
 
-/// Returns the width in pixels a char will occupy on the screen. The width is constant for all
-/// characters regarding the same combination of [`FontWeight`] and [`RasterHeight`]. The width is
-/// a few percent smaller than the height of each char
+/// Returns the width in pixels a char will occupy on the screen.
+///
+/// The width is constant for all characters regarding the same combination of [`FontWeight`] and
+/// [`RasterHeight`]. The width is a few percent smaller than the height of each char. Wide chars
+/// occupy two cells of this width, see [`RasterizedChar::cell_width`].
 #[inline]
 pub const fn get_raster_width(style: FontWeight, size: RasterHeight) -> usize {
     match style {
         #[cfg(feature = "light")]
         FontWeight::Light => match size {
+            #[cfg(feature = "size_8")]
+            RasterHeight::Size8 => crate::light::size_8::RASTER_WIDTH,
+            #[cfg(feature = "size_10")]
+            RasterHeight::Size10 => crate::light::size_10::RASTER_WIDTH,
+            #[cfg(feature = "size_12")]
+            RasterHeight::Size12 => crate::light::size_12::RASTER_WIDTH,
+            #[cfg(feature = "size_14")]
+            RasterHeight::Size14 => crate::light::size_14::RASTER_WIDTH,
             #[cfg(feature = "size_16")]
             RasterHeight::Size16 => crate::light::size_16::RASTER_WIDTH,
             #[cfg(feature = "size_20")]
@@ -248,9 +663,23 @@ pub const fn get_raster_width(style: FontWeight, size: RasterHeight) -> usize {
             RasterHeight::Size24 => crate::light::size_24::RASTER_WIDTH,
             #[cfg(feature = "size_32")]
             RasterHeight::Size32 => crate::light::size_32::RASTER_WIDTH,
+            #[cfg(feature = "size_40")]
+            RasterHeight::Size40 => crate::light::size_40::RASTER_WIDTH,
+            #[cfg(feature = "size_48")]
+            RasterHeight::Size48 => crate::light::size_48::RASTER_WIDTH,
+            #[cfg(feature = "size_64")]
+            RasterHeight::Size64 => crate::light::size_64::RASTER_WIDTH,
         },
         #[cfg(feature = "regular")]
         FontWeight::Regular => match size {
+            #[cfg(feature = "size_8")]
+            RasterHeight::Size8 => crate::regular::size_8::RASTER_WIDTH,
+            #[cfg(feature = "size_10")]
+            RasterHeight::Size10 => crate::regular::size_10::RASTER_WIDTH,
+            #[cfg(feature = "size_12")]
+            RasterHeight::Size12 => crate::regular::size_12::RASTER_WIDTH,
+            #[cfg(feature = "size_14")]
+            RasterHeight::Size14 => crate::regular::size_14::RASTER_WIDTH,
             #[cfg(feature = "size_16")]
             RasterHeight::Size16 => crate::regular::size_16::RASTER_WIDTH,
             #[cfg(feature = "size_20")]
@@ -259,9 +688,23 @@ pub const fn get_raster_width(style: FontWeight, size: RasterHeight) -> usize {
             RasterHeight::Size24 => crate::regular::size_24::RASTER_WIDTH,
             #[cfg(feature = "size_32")]
             RasterHeight::Size32 => crate::regular::size_32::RASTER_WIDTH,
+            #[cfg(feature = "size_40")]
+            RasterHeight::Size40 => crate::regular::size_40::RASTER_WIDTH,
+            #[cfg(feature = "size_48")]
+            RasterHeight::Size48 => crate::regular::size_48::RASTER_WIDTH,
+            #[cfg(feature = "size_64")]
+            RasterHeight::Size64 => crate::regular::size_64::RASTER_WIDTH,
         },
         #[cfg(feature = "bold")]
         FontWeight::Bold => match size {
+            #[cfg(feature = "size_8")]
+            RasterHeight::Size8 => crate::bold::size_8::RASTER_WIDTH,
+            #[cfg(feature = "size_10")]
+            RasterHeight::Size10 => crate::bold::size_10::RASTER_WIDTH,
+            #[cfg(feature = "size_12")]
+            RasterHeight::Size12 => crate::bold::size_12::RASTER_WIDTH,
+            #[cfg(feature = "size_14")]
+            RasterHeight::Size14 => crate::bold::size_14::RASTER_WIDTH,
             #[cfg(feature = "size_16")]
             RasterHeight::Size16 => crate::bold::size_16::RASTER_WIDTH,
             #[cfg(feature = "size_20")]
@@ -270,8 +713,112 @@ pub const fn get_raster_width(style: FontWeight, size: RasterHeight) -> usize {
             RasterHeight::Size24 => crate::bold::size_24::RASTER_WIDTH,
             #[cfg(feature = "size_32")]
             RasterHeight::Size32 => crate::bold::size_32::RASTER_WIDTH,
+            #[cfg(feature = "size_40")]
+            RasterHeight::Size40 => crate::bold::size_40::RASTER_WIDTH,
+            #[cfg(feature = "size_48")]
+            RasterHeight::Size48 => crate::bold::size_48::RASTER_WIDTH,
+            #[cfg(feature = "size_64")]
+            RasterHeight::Size64 => crate::bold::size_64::RASTER_WIDTH,
         },
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_font_weight_try_from_round_trip() {
+        for &weight in FontWeight::ALL {
+            assert_eq!(FontWeight::try_from(weight.val()), Ok(weight));
+        }
+    }
+
+    #[test]
+    fn test_raster_height_try_from_round_trip() {
+        for &height in RasterHeight::ALL {
+            assert_eq!(RasterHeight::try_from(height.val()), Ok(height));
+        }
+    }
+
+    #[test]
+    fn test_try_from_unsupported_value() {
+        assert_eq!(
+            FontWeight::try_from(usize::MAX),
+            Err(UnsupportedValueError(usize::MAX))
+        );
+        assert_eq!(RasterHeight::try_from(0), Err(UnsupportedValueError(0)));
+        assert_eq!(RasterHeight::try_from(15), Err(UnsupportedValueError(15)));
+        assert_eq!(
+            RasterHeight::try_from(usize::MAX),
+            Err(UnsupportedValueError(usize::MAX))
+        );
+    }
+
+    #[test]
+    fn test_font_weight_all_matches_features() {
+        // the value of each font weight and whether its feature is selected
+        let features: &[(usize, bool)] = &[
+            (0, cfg!(feature = "light")),
+            (1, cfg!(feature = "regular")),
+            (2, cfg!(feature = "bold")),
+        ];
+        let selected = features
+            .iter()
+            .filter(|(_, selected)| *selected)
+            .map(|(val, _)| *val);
+        assert!(selected.eq(FontWeight::ALL.iter().map(|w| w.val())));
+    }
+
+    #[test]
+    fn test_raster_height_all_matches_features() {
+        // each raster height and whether its feature is selected
+        let features: &[(usize, bool)] = &[
+            (8, cfg!(feature = "size_8")),
+            (10, cfg!(feature = "size_10")),
+            (12, cfg!(feature = "size_12")),
+            (14, cfg!(feature = "size_14")),
+            (16, cfg!(feature = "size_16")),
+            (20, cfg!(feature = "size_20")),
+            (24, cfg!(feature = "size_24")),
+            (32, cfg!(feature = "size_32")),
+            (40, cfg!(feature = "size_40")),
+            (48, cfg!(feature = "size_48")),
+            (64, cfg!(feature = "size_64")),
+        ];
+        let selected = features
+            .iter()
+            .filter(|(_, selected)| *selected)
+            .map(|(val, _)| *val);
+        assert!(selected.eq(RasterHeight::ALL.iter().map(|h| h.val())));
+        assert!(RasterHeight::ALL
+            .windows(2)
+            .all(|pair| pair[0].val() < pair[1].val()));
+    }
+
+    #[test]
+    fn test_raster_height_for_grid_overflow() {
+        for &weight in FontWeight::ALL {
+            assert_eq!(
+                raster_height_for_grid(weight, usize::MAX, usize::MAX, usize::MAX, 1),
+                None
+            );
+            assert_eq!(
+                raster_height_for_grid(weight, usize::MAX, usize::MAX, 1, usize::MAX),
+                None
+            );
+        }
+    }
+
+    #[test]
+    fn test_raster_height_for_grid_zero_columns_or_rows() {
+        let biggest = RasterHeight::ALL.last().copied();
+        for &weight in FontWeight::ALL {
+            assert_eq!(raster_height_for_grid(weight, 0, 1000, 0, 1), biggest);
+            assert_eq!(raster_height_for_grid(weight, 1000, 0, 1, 0), biggest);
+            assert_eq!(raster_height_for_grid(weight, 0, 0, 0, 0), biggest);
+        }
+    }
+}
+
 // # THIS FILE GETS AUTO GENERATED BY THE PROJECT IN "../codegen" (see repository!)
@@ -0,0 +1,538 @@
+//! Parser for Linux console fonts (PSF) as an alternative [`GlyphSource`].
+//!
+//! [`PsfFont`] reads PSF1 and PSF2 fonts, such as Terminus or Unifont, from a `&[u8]`, e.g.,
+//! from `include_bytes!`. It doesn't allocate or copy anything: glyphs are [`Glyph`]s that
+//! point into the font data, and their 1-bit pixels are expanded to the intensities 0 and 255.
+//! Hence, the rest of a renderer stays the same as with the rasters of this crate.
+//!
+//! Chars are mapped to glyphs with the unicode table of the font. Fonts without a unicode table
+//! map each code point to the glyph with the same index. Lookups in the unicode table search it
+//! from the beginning. Sequences of the unicode table, i.e., entries for chars with combining
+//! marks, are ignored.
+//!
+//! ```rust
+//! use noto_sans_mono_bitmap::glyph_source::{GlyphSource, NotoSansMono};
+//! use noto_sans_mono_bitmap::psf::PsfFont;
+//! use noto_sans_mono_bitmap::{FontWeight, RasterHeight};
+//!
+//! // usually: include_bytes!("ter-u16n.psf")
+//! let data: &[u8] = &[
+//!     0x72, 0xb5, 0x4a, 0x86, // magic
+//!     0, 0, 0, 0, // version
+//!     32, 0, 0, 0, // header size
+//!     1, 0, 0, 0, // flags: has a unicode table
+//!     1, 0, 0, 0, // number of glyphs
+//!     2, 0, 0, 0, // bytes per glyph
+//!     2, 0, 0, 0, // height
+//!     8, 0, 0, 0, // width
+//!     0b1000_0001, 0b0111_1110, // glyph 0
+//!     b'A', 0xff, // unicode table: glyph 0 is 'A'
+//! ];
+//! let psf = PsfFont::parse(data).unwrap();
+//! let glyph = psf.glyph('A').unwrap();
+//! assert_eq!((glyph.width(), glyph.height()), (8, 2));
+//! assert_eq!(glyph.pixel(0, 0), 255);
+//! assert_eq!(glyph.pixel(1, 0), 0);
+//!
+//! // fall back to Noto Sans Mono for chars that the PSF font doesn't have
+//! let font = psf.or(NotoSansMono::new(FontWeight::Regular, RasterHeight::Size16));
+//! assert!(font.glyph('B').is_some());
+//! ```
+
+// # THIS FILE GETS AUTO GENERATED BY THE PROJECT IN "../codegen" (see repository!)
+
+use crate::glyph_source::{Glyph, GlyphSource};
+use core::convert::TryFrom;
+use core::fmt;
+
+/// Magic bytes at the beginning of a PSF1 font.
+const PSF1_MAGIC: [u8; 2] = [0x36, 0x04];
+/// Magic bytes at the beginning of a PSF2 font.
+const PSF2_MAGIC: [u8; 4] = [0x72, 0xb5, 0x4a, 0x86];
+
+/// PSF1 mode flag: the font has 512 instead of 256 glyphs.
+const PSF1_MODE512: u8 = 0x01;
+/// PSF1 mode flag: the font has a unicode table.
+const PSF1_MODEHASTAB: u8 = 0x02;
+/// PSF1 mode flag: the font has a unicode table with sequences.
+const PSF1_MODEHASSEQ: u8 = 0x04;
+/// PSF1 unicode table: ends the entries of a glyph.
+const PSF1_SEPARATOR: u16 = 0xffff;
+/// PSF1 unicode table: starts the sequences of a glyph.
+const PSF1_STARTSEQ: u16 = 0xfffe;
+
+/// PSF2 header flag: the font has a unicode table.
+const PSF2_HAS_UNICODE_TABLE: u32 = 0x01;
+/// PSF2 unicode table: ends the entries of a glyph.
+const PSF2_SEPARATOR: u8 = 0xff;
+/// PSF2 unicode table: starts the sequences of a glyph.
+const PSF2_STARTSEQ: u8 = 0xfe;
+
+/// Version of the PSF format.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PsfVersion {
+    /// PSF1: glyphs are 8 pixels wide, unicode tables use UCS-2.
+    Psf1,
+    /// PSF2: glyphs have any width, unicode tables use UTF-8.
+    Psf2,
+}
+
+/// Error of [`PsfFont::parse`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PsfError {
+    /// The data starts with neither the PSF1 nor the PSF2 magic bytes.
+    UnknownMagic,
+    /// The header contains values that make no sense, such as glyphs without pixels.
+    InvalidHeader,
+    /// The data is shorter than the header says.
+    Truncated,
+}
+
+impl fmt::Display for PsfError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = match self {
+            Self::UnknownMagic => "not a PSF1 or PSF2 font",
+            Self::InvalidHeader => "invalid PSF header",
+            Self::Truncated => "PSF font is truncated",
+        };
+        f.write_str(msg)
+    }
+}
+
+/// A PSF1 or PSF2 font. See the [module description](self).
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct PsfFont<'a> {
+    version: PsfVersion,
+    /// The bitmaps of all glyphs.
+    glyphs: &'a [u8],
+    glyph_count: usize,
+    bytes_per_glyph: usize,
+    width: usize,
+    height: usize,
+    unicode_table: Option<&'a [u8]>,
+}
+
+impl<'a> PsfFont<'a> {
+    /// Parses a PSF1 or PSF2 font.
+    pub fn parse(data: &'a [u8]) -> Result<Self, PsfError> {
+        if data.starts_with(&PSF2_MAGIC) {
+            Self::parse_psf2(data)
+        } else if data.starts_with(&PSF1_MAGIC) {
+            Self::parse_psf1(data)
+        } else {
+            Err(PsfError::UnknownMagic)
+        }
+    }
+
+    fn parse_psf1(data: &'a [u8]) -> Result<Self, PsfError> {
+        let (mode, height) = match data {
+            [_, _, mode, height, ..] => (*mode, usize::from(*height)),
+            _ => return Err(PsfError::Truncated),
+        };
+        if height == 0 {
+            return Err(PsfError::InvalidHeader);
+        }
+        let glyph_count = if mode & PSF1_MODE512 != 0 { 512 } else { 256 };
+        let (glyphs, unicode_table) = split_glyphs(data, 4, glyph_count, height)?;
+        Ok(Self {
+            version: PsfVersion::Psf1,
+            glyphs,
+            glyph_count,
+            bytes_per_glyph: height,
+            width: 8,
+            height,
+            unicode_table: (mode & (PSF1_MODEHASTAB | PSF1_MODEHASSEQ) != 0).then(|| unicode_table),
+        })
+    }
+
+    fn parse_psf2(data: &'a [u8]) -> Result<Self, PsfError> {
+        let field = |i: usize| {
+            data.get(i * 4..i * 4 + 4)
+                .map(|bytes| u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as usize)
+                .ok_or(PsfError::Truncated)
+        };
+        let header_size = field(2)?;
+        let flags = field(3)? as u32;
+        let glyph_count = field(4)?;
+        let bytes_per_glyph = field(5)?;
+        let height = field(6)?;
+        let width = field(7)?;
+        if header_size < 32
+            || width == 0
+            || height == 0
+            || bytes_per_glyph < height.saturating_mul((width + 7) / 8)
+        {
+            return Err(PsfError::InvalidHeader);
+        }
+        let (glyphs, unicode_table) =
+            split_glyphs(data, header_size, glyph_count, bytes_per_glyph)?;
+        Ok(Self {
+            version: PsfVersion::Psf2,
+            glyphs,
+            glyph_count,
+            bytes_per_glyph,
+            width,
+            height,
+            unicode_table: (flags & PSF2_HAS_UNICODE_TABLE != 0).then(|| unicode_table),
+        })
+    }
+
+    /// The version of the PSF format.
+    #[inline]
+    pub const fn version(&self) -> PsfVersion {
+        self.version
+    }
+
+    /// Width of each glyph in pixels.
+    #[inline]
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Height of each glyph in pixels.
+    #[inline]
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Number of glyphs in the font.
+    #[inline]
+    pub const fn glyph_count(&self) -> usize {
+        self.glyph_count
+    }
+
+    /// Returns true if the font maps chars to glyphs with a unicode table.
+    #[inline]
+    pub const fn has_unicode_table(&self) -> bool {
+        self.unicode_table.is_some()
+    }
+
+    /// Returns the glyph with the given index.
+    pub fn glyph_by_index(&self, index: usize) -> Option<Glyph<'a>> {
+        if index >= self.glyph_count {
+            return None;
+        }
+        let begin = index * self.bytes_per_glyph;
+        let bitmap = &self.glyphs[begin..begin + self.bytes_per_glyph];
+        Some(Glyph::from_bits(bitmap, self.width, self.height))
+    }
+
+    /// Returns the index of the glyph of a char. See the [module description](self).
+    pub fn glyph_index(&self, c: char) -> Option<usize> {
+        let index = match (self.unicode_table, self.version) {
+            (None, _) => c as usize,
+            (Some(table), PsfVersion::Psf1) => psf1_glyph_index(table, c)?,
+            (Some(table), PsfVersion::Psf2) => psf2_glyph_index(table, c)?,
+        };
+        if index < self.glyph_count {
+            Some(index)
+        } else {
+            None
+        }
+    }
+}
+
+impl GlyphSource for PsfFont<'_> {
+    fn glyph(&self, c: char) -> Option<Glyph<'_>> {
+        self.glyph_by_index(self.glyph_index(c)?)
+    }
+}
+
+/// Splits the data after the header into the bitmaps of all glyphs and the rest, which is the
+/// unicode table, if there is one.
+fn split_glyphs(
+    data: &[u8],
+    header_size: usize,
+    glyph_count: usize,
+    bytes_per_glyph: usize,
+) -> Result<(&[u8], &[u8]), PsfError> {
+    let glyphs_end = glyph_count
+        .checked_mul(bytes_per_glyph)
+        .and_then(|size| size.checked_add(header_size))
+        .ok_or(PsfError::InvalidHeader)?;
+    let glyphs = data
+        .get(header_size..glyphs_end)
+        .ok_or(PsfError::Truncated)?;
+    Ok((glyphs, &data[glyphs_end..]))
+}
+
+/// Searches the UCS-2 unicode table of a PSF1 font.
+fn psf1_glyph_index(table: &[u8], c: char) -> Option<usize> {
+    let c = u16::try_from(c as u32).ok()?;
+    let mut index = 0;
+    let mut in_sequences = false;
+    for entry in table.chunks_exact(2) {
+        match u16::from_le_bytes([entry[0], entry[1]]) {
+            PSF1_SEPARATOR => {
+                index += 1;
+                in_sequences = false;
+            }
+            PSF1_STARTSEQ => in_sequences = true,
+            entry if entry == c && !in_sequences => return Some(index),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Searches the UTF-8 unicode table of a PSF2 font.
+fn psf2_glyph_index(table: &[u8], c: char) -> Option<usize> {
+    table
+        .split(|&byte| byte == PSF2_SEPARATOR)
+        .position(|entries| {
+            let chars = entries
+                .split(|&byte| byte == PSF2_STARTSEQ)
+                .next()
+                .unwrap_or(&[]);
+            core::str::from_utf8(chars).map_or(false, |chars| chars.contains(c))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use std::vec::Vec;
+
+    /// A PSF1 font whose glyph `i` has the byte `i` in each row.
+    fn psf1(mode: u8, height: u8, table: &[u16]) -> Vec<u8> {
+        let glyph_count = if mode & PSF1_MODE512 != 0 { 512 } else { 256 };
+        let mut data = [PSF1_MAGIC[0], PSF1_MAGIC[1], mode, height].to_vec();
+        for index in 0..glyph_count {
+            data.extend((0..height).map(|_| index as u8));
+        }
+        data.extend(table.iter().flat_map(|entry| entry.to_le_bytes()));
+        data
+    }
+
+    /// A PSF2 font with the header fields header size, flags, number of glyphs, bytes per glyph,
+    /// height, and width, followed by `rest`.
+    fn psf2(header: [u32; 6], rest: &[u8]) -> Vec<u8> {
+        let mut data = [PSF2_MAGIC, 0_u32.to_le_bytes()].concat();
+        data.extend(header.iter().flat_map(|field| field.to_le_bytes()));
+        data.extend(rest);
+        data
+    }
+
+    /// A PSF2 unicode table with an entry per glyph, each with its chars and sequences.
+    fn psf2_table(entries: &[(&str, &[&str])]) -> Vec<u8> {
+        let mut table = Vec::new();
+        for (chars, sequences) in entries {
+            table.extend(chars.as_bytes());
+            for sequence in *sequences {
+                table.push(PSF2_STARTSEQ);
+                table.extend(sequence.as_bytes());
+            }
+            table.push(PSF2_SEPARATOR);
+        }
+        table
+    }
+
+    #[test]
+    fn test_psf1() {
+        let data = psf1(0, 2, &[]);
+        let font = PsfFont::parse(&data).unwrap();
+        assert_eq!(font.version(), PsfVersion::Psf1);
+        assert_eq!((font.width(), font.height()), (8, 2));
+        assert_eq!(font.glyph_count(), 256);
+        assert!(!font.has_unicode_table());
+        assert_eq!(font.glyph_index('A'), Some(0x41));
+
+        // 0x81 = 0b1000_0001
+        let glyph = font.glyph('\u{81}').unwrap();
+        assert_eq!((glyph.width(), glyph.height()), (8, 2));
+        assert_eq!(glyph.pixel(0, 1), 255);
+        assert_eq!(glyph.pixel(1, 1), 0);
+        assert_eq!(glyph.pixel(7, 1), 255);
+        assert_eq!(glyph.pixel(8, 1), 0);
+        assert_eq!(glyph.pixel(0, 2), 0);
+    }
+
+    #[test]
+    fn test_psf1_512_with_unicode_table() {
+        let table = [
+            // glyph 0: 'A' and 'Ä'
+            0x41,
+            0xc4,
+            PSF1_SEPARATOR,
+            // glyph 1: 'Ω' and the sequence "e\u{301}"
+            0x3a9,
+            PSF1_STARTSEQ,
+            0x65,
+            0x301,
+            PSF1_SEPARATOR,
+            // glyph 2: 'é'
+            0xe9,
+            PSF1_SEPARATOR,
+        ];
+        let data = psf1(PSF1_MODE512 | PSF1_MODEHASTAB, 1, &table);
+        let font = PsfFont::parse(&data).unwrap();
+        assert_eq!(font.glyph_count(), 512);
+        assert!(font.has_unicode_table());
+        assert_eq!(font.glyph_index('A'), Some(0));
+        assert_eq!(font.glyph_index('Ä'), Some(0));
+        assert_eq!(font.glyph_index('Ω'), Some(1));
+        assert_eq!(font.glyph_index('é'), Some(2));
+        // glyph 511 has the byte 0xff in each row
+        assert_eq!(font.glyph_by_index(511).unwrap().pixel(7, 0), 255);
+
+        // a table with sequences is a unicode table as well
+        let data = psf1(PSF1_MODEHASSEQ, 1, &table);
+        let font = PsfFont::parse(&data).unwrap();
+        assert_eq!(font.glyph_count(), 256);
+        assert!(font.has_unicode_table());
+        assert_eq!(font.glyph_index('Ω'), Some(1));
+    }
+
+    #[test]
+    fn test_psf2_unicode_table_with_sequences() {
+        // 10 pixels wide: two bytes per row, the last six bits of each row are padding
+        let glyphs = [
+            [0b1000_0000, 0b0100_0000],
+            [0b0000_0001, 0b1000_0000],
+            [0, 0],
+            [0, 0],
+        ];
+        let table = psf2_table(&[
+            ("A", &[]),
+            ("Å", &["A\u{30a}"]),
+            ("", &["e\u{301}", "e\u{301}\u{302}"]),
+            ("é€", &[]),
+        ]);
+        let data = psf2(
+            [32, PSF2_HAS_UNICODE_TABLE, 4, 2, 1, 10],
+            &[glyphs.concat(), table].concat(),
+        );
+        let font = PsfFont::parse(&data).unwrap();
+        assert_eq!(font.version(), PsfVersion::Psf2);
+        assert_eq!((font.width(), font.height()), (10, 1));
+        assert_eq!(font.glyph_count(), 4);
+        assert!(font.has_unicode_table());
+        assert_eq!(font.glyph_index('A'), Some(0));
+        assert_eq!(font.glyph_index('Å'), Some(1));
+        assert_eq!(font.glyph_index('é'), Some(3));
+        assert_eq!(font.glyph_index('€'), Some(3));
+
+        let glyph = font.glyph('A').unwrap();
+        assert_eq!(glyph.pixel(0, 0), 255);
+        assert_eq!(glyph.pixel(9, 0), 255);
+        assert_eq!(glyph.pixel(8, 0), 0);
+        let glyph = font.glyph('Å').unwrap();
+        assert_eq!(glyph.pixel(7, 0), 255);
+        assert_eq!(glyph.pixel(8, 0), 255);
+        // padding bits are outside of the glyph
+        assert_eq!(glyph.pixel(10, 0), 0);
+    }
+
+    #[test]
+    fn test_psf2_header_size() {
+        // the glyphs start after the header, which may be bigger than 32 bytes
+        let data = psf2([36, 0, 1, 1, 1, 8], &[0, 0, 0, 0, 0xff]);
+        let font = PsfFont::parse(&data).unwrap();
+        assert_eq!(font.glyph_by_index(0).unwrap().pixel(0, 0), 255);
+    }
+
+    #[test]
+    fn test_glyph_index_misses() {
+        // without a unicode table, chars beyond the last glyph miss
+        let data = psf2([32, 0, 4, 1, 1, 8], &[0; 4]);
+        let font = PsfFont::parse(&data).unwrap();
+        assert!(!font.has_unicode_table());
+        assert_eq!(font.glyph_index('\u{3}'), Some(3));
+        assert_eq!(font.glyph_index('\u{4}'), None);
+        assert!(font.glyph('\u{4}').is_none());
+        assert!(font.glyph_by_index(4).is_none());
+
+        // chars that are missing from the unicode table, that only appear in sequences, or that
+        // map to glyphs beyond the last glyph miss
+        let table = psf2_table(&[("A", &[]), ("", &["e\u{301}"]), ("B", &[])]);
+        let data = psf2(
+            [32, PSF2_HAS_UNICODE_TABLE, 2, 1, 1, 8],
+            &[[0, 0].as_ref(), &table].concat(),
+        );
+        let font = PsfFont::parse(&data).unwrap();
+        assert_eq!(font.glyph_index('A'), Some(0));
+        assert_eq!(font.glyph_index('C'), None);
+        assert_eq!(font.glyph_index('e'), None);
+        assert_eq!(font.glyph_index('\u{301}'), None);
+        assert_eq!(font.glyph_index('B'), None);
+        assert!(font.glyph('B').is_none());
+
+        // PSF1 tables can't contain chars beyond U+FFFF, and sequences are ignored as well
+        let table = [0x41, PSF1_STARTSEQ, 0x65, 0x301, PSF1_SEPARATOR];
+        let data = psf1(PSF1_MODEHASTAB, 1, &table);
+        let font = PsfFont::parse(&data).unwrap();
+        assert_eq!(font.glyph_index('A'), Some(0));
+        assert_eq!(font.glyph_index('B'), None);
+        assert_eq!(font.glyph_index('e'), None);
+        assert_eq!(font.glyph_index('\u{1f600}'), None);
+    }
+
+    #[test]
+    fn test_truncated() {
+        assert_eq!(PsfFont::parse(&PSF1_MAGIC), Err(PsfError::Truncated));
+        let data = psf1(0, 2, &[]);
+        assert_eq!(PsfFont::parse(&data[..3]), Err(PsfError::Truncated));
+        assert_eq!(
+            PsfFont::parse(&data[..data.len() - 1]),
+            Err(PsfError::Truncated)
+        );
+        let data = psf1(PSF1_MODE512, 2, &[]);
+        assert_eq!(
+            PsfFont::parse(&data[..4 + 256 * 2]),
+            Err(PsfError::Truncated)
+        );
+
+        let data = psf2([32, 0, 2, 1, 1, 8], &[0, 0]);
+        assert!(PsfFont::parse(&data).is_ok());
+        assert_eq!(PsfFont::parse(&data[..4]), Err(PsfError::Truncated));
+        assert_eq!(PsfFont::parse(&data[..31]), Err(PsfError::Truncated));
+        assert_eq!(PsfFont::parse(&data[..33]), Err(PsfError::Truncated));
+        // the header is bigger than the data
+        let data = psf2([64, 0, 2, 1, 1, 8], &[0, 0]);
+        assert_eq!(PsfFont::parse(&data), Err(PsfError::Truncated));
+    }
+
+    #[test]
+    fn test_bad_magic() {
+        assert_eq!(PsfFont::parse(&[]), Err(PsfError::UnknownMagic));
+        assert_eq!(PsfFont::parse(&[0x36]), Err(PsfError::UnknownMagic));
+        assert_eq!(
+            PsfFont::parse(&[0x36, 0x05, 0, 8]),
+            Err(PsfError::UnknownMagic)
+        );
+        let mut data = psf2([32, 0, 1, 1, 1, 8], &[0]);
+        data[3] = 0;
+        assert_eq!(PsfFont::parse(&data), Err(PsfError::UnknownMagic));
+    }
+
+    #[test]
+    fn test_bad_header_sizes() {
+        // PSF1 glyphs without rows
+        assert_eq!(
+            PsfFont::parse(&psf1(0, 0, &[])),
+            Err(PsfError::InvalidHeader)
+        );
+
+        let glyphs = [0; 16];
+        // header size smaller than the header
+        let data = psf2([28, 0, 1, 1, 1, 8], &glyphs);
+        assert_eq!(PsfFont::parse(&data), Err(PsfError::InvalidHeader));
+        // no width or height
+        let data = psf2([32, 0, 1, 1, 1, 0], &glyphs);
+        assert_eq!(PsfFont::parse(&data), Err(PsfError::InvalidHeader));
+        let data = psf2([32, 0, 1, 1, 0, 8], &glyphs);
+        assert_eq!(PsfFont::parse(&data), Err(PsfError::InvalidHeader));
+        // glyphs with less bytes than their pixels need
+        let data = psf2([32, 0, 1, 3, 2, 9], &glyphs);
+        assert_eq!(PsfFont::parse(&data), Err(PsfError::InvalidHeader));
+        let data = psf2([32, 0, 1, 4, 2, 9], &glyphs);
+        assert!(PsfFont::parse(&data).is_ok());
+        // the glyphs would be bigger than the address space
+        let data = psf2([32, 0, u32::MAX, u32::MAX, 1, 8], &glyphs);
+        assert!(PsfFont::parse(&data).is_err());
+    }
+}
+
+// # THIS FILE GETS AUTO GENERATED BY THE PROJECT IN "../codegen" (see repository!)
@@ -0,0 +1,39 @@
+//! Tables that look up the rasters of a unicode range for a font weight and a raster height.
+//!
+//! The rasters of all chars of a unicode range with the same width are packed into a single
+//! file, one after another. Each raster is stored row by row with one byte per pixel. Hence,
+//! the raster of the n-th char of a table starts at the offset `n * width * height` and the
+//! bytes are used as they are. Nothing is decoded during compile time or runtime.
+
+// # THIS FILE GETS AUTO GENERATED BY THE PROJECT IN "../codegen" (see repository!)
+
+/// The packed rasters of the chars of a unicode range that have the same width.
+#[derive(Debug)]
+pub(crate) struct RasterTable {
+    /// The chars of the table in ascending order.
+    chars: &'static [char],
+    /// The rasters of all chars in the same order.
+    pixels: &'static [u8],
+}
+
+impl RasterTable {
+    /// Creates a table from the chars in ascending order and their packed rasters.
+    pub(crate) const fn new(chars: &'static [char], pixels: &'static [u8]) -> Self {
+        Self { chars, pixels }
+    }
+
+    /// Returns the pixels of the raster of the char, row by row, or `None`, if the table
+    /// doesn't have the char.
+    pub(crate) fn get(&self, c: char) -> Option<&'static [u8]> {
+        // most lookups miss the table, as each char is only in the table of its unicode range
+        match (self.chars.first(), self.chars.last()) {
+            (Some(&first), Some(&last)) if first <= c && c <= last => {}
+            _ => return None,
+        }
+        let index = self.chars.binary_search(&c).ok()?;
+        let size = self.pixels.len() / self.chars.len();
+        self.pixels.get(index * size..(index + 1) * size)
+    }
+}
+
+// # THIS FILE GETS AUTO GENERATED BY THE PROJECT IN "../codegen" (see repository!)